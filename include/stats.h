@@ -0,0 +1,53 @@
+/*
+ * Copyright © 2019 Jubal Gonzalez-Santos
+ * [This program is licensed under the "MIT License"]
+ * Please see the file LICENSE in the source
+ * distribution of this software for license terms.
+ */
+
+/* C interface to the stats library, built with the `ffi`
+ * feature. Each function returns one of the status codes
+ * below, writing its result through `out` on STATS_OK. */
+
+#ifndef STATS_H
+#define STATS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define STATS_OK 0
+#define STATS_NULL_POINTER 1
+#define STATS_UNDEFINED 2
+
+int stats_mean(const double *nums, size_t len, double *out);
+int stats_stddev(const double *nums, size_t len, double *out);
+int stats_median(const double *nums, size_t len, double *out);
+int stats_l2(const double *nums, size_t len, double *out);
+int stats_mad(const double *nums, size_t len, double *out);
+int stats_quantile(const double *nums, size_t len, double q, double *out);
+
+/* Running summary, released with stats_summary_free. */
+typedef struct stats_summary stats_summary;
+
+stats_summary *stats_summary_new(void);
+void stats_summary_free(stats_summary *summary);
+int stats_summary_push(stats_summary *summary, double x);
+int stats_summary_push_all(stats_summary *summary, const double *nums, size_t len);
+/* summary and other may be the same. */
+int stats_summary_merge(stats_summary *summary, const stats_summary *other);
+int stats_summary_count(const stats_summary *summary, uint64_t *out);
+int stats_summary_mean(const stats_summary *summary, double *out);
+int stats_summary_variance(const stats_summary *summary, double *out);
+int stats_summary_stddev(const stats_summary *summary, double *out);
+int stats_summary_min(const stats_summary *summary, double *out);
+int stats_summary_max(const stats_summary *summary, double *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,110 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of `ndarray` arrays. Any statistic on slices
+//! applies to a one-dimensional view, and along either axis
+//! of a two-dimensional one. As in `ndarray`, reducing
+//! along `Axis(0)` gives one value per column and along
+//! `Axis(1)` one value per row.
+
+use ndarray::{Array1, ArrayView1, ArrayView2, Axis};
+
+/// Value of `statistic` on a one-dimensional view, copying
+/// only if the view is not contiguous. Any `StatFn` can
+/// serve as the statistic.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::arrays::*;
+/// use ndarray::array;
+/// let a = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+/// assert_eq!(Some(3.0), apply(a.column(0), stats::mean));
+/// assert_eq!(Some(1.0), apply(a.row(0), stats::median));
+/// ```
+pub fn apply<F>(view: ArrayView1<f64>, statistic: F) -> Option<f64>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    match view.as_slice() {
+        Some(slice) => statistic(slice),
+        None => statistic(&view.to_vec()),
+    }
+}
+
+/// Value of `statistic` for each lane along `axis` of a
+/// two-dimensional view. The result is undefined if the
+/// statistic is undefined for any lane.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::arrays::*;
+/// use ndarray::{array, Axis};
+/// let a = array![[3.0, 0.0], [4.0, 5.0], [0.0, 12.0]];
+/// assert_eq!(Some(array![5.0, 13.0]), along_axis(a.view(), Axis(0), stats::l2));
+/// assert_eq!(Some(array![0.0, 4.0, 0.0]), along_axis(a.view(), Axis(1), stats::median));
+/// ```
+pub fn along_axis<F>(array: ArrayView2<f64>, axis: Axis, statistic: F) -> Option<Array1<f64>>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    array
+        .axis_iter(Axis(1 - axis.index()))
+        .map(|lane| apply(lane, &statistic))
+        .collect::<Option<Vec<f64>>>()
+        .map(Array1::from)
+}
+
+/// Mean of each lane along `axis`, undefined for an empty
+/// lane.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::arrays::*;
+/// use ndarray::{array, Array2, Axis};
+/// let a = array![[1.0, 2.0], [3.0, 4.0]];
+/// assert_eq!(Some(array![2.0, 3.0]), mean_axis(a.view(), Axis(0)));
+/// assert_eq!(Some(array![1.5, 3.5]), mean_axis(a.view(), Axis(1)));
+/// assert_eq!(None, mean_axis(Array2::<f64>::zeros((0, 2)).view(), Axis(0)));
+/// ```
+pub fn mean_axis(array: ArrayView2<f64>, axis: Axis) -> Option<Array1<f64>> {
+    if array.len_of(axis) == 0 {
+        return None;
+    }
+    along_axis(array, axis, crate::mean)
+}
+
+/// Population standard deviation of each lane along
+/// `axis`, undefined for an empty lane.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::arrays::*;
+/// use ndarray::{array, Axis};
+/// let a = array![[1.0, 2.0], [-2.0, 2.0]];
+/// assert_eq!(Some(array![1.5, 0.0]), stddev_axis(a.view(), Axis(0)));
+/// ```
+pub fn stddev_axis(array: ArrayView2<f64>, axis: Axis) -> Option<Array1<f64>> {
+    along_axis(array, axis, crate::stddev)
+}
+
+/// Quantile `q` of each lane along `axis`, as for
+/// `quantile`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::arrays::*;
+/// use ndarray::{array, Axis};
+/// let a = array![[1.0, 10.0], [2.0, 20.0], [3.0, 30.0], [4.0, 40.0]];
+/// assert_eq!(Some(array![2.5, 25.0]), quantile_axis(a.view(), Axis(0), 0.5));
+/// assert_eq!(None, quantile_axis(a.view(), Axis(0), 1.5));
+/// ```
+pub fn quantile_axis(array: ArrayView2<f64>, axis: Axis, q: f64) -> Option<Array1<f64>> {
+    along_axis(array, axis, |lane| crate::quantile(lane, q))
+}
@@ -0,0 +1,107 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Conformity of first digits to Benford's law, as used in
+//! fraud and data-quality auditing.
+
+use crate::{chi_square_gof, TestResult};
+
+/// Outcome of a first-digit Benford analysis. Index `d - 1`
+/// of each array is for leading digit `d`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenfordAnalysis {
+    /// Number of values analyzed: those that are finite and
+    /// nonzero.
+    pub count: usize,
+    /// Observed fraction of values with each leading digit.
+    pub observed: [f64; 9],
+    /// Fraction expected under Benford's law, `log10(1 + 1/d)`.
+    pub expected: [f64; 9],
+    /// Chi-square goodness-of-fit test of the digit counts
+    /// against Benford's law, undefined (as for
+    /// `chi_square_gof`) for fewer than about 110 values.
+    pub chi_square: Option<TestResult>,
+    /// Mean absolute deviation of the observed from the
+    /// expected fractions. Nigrini's thresholds for first
+    /// digits take below 0.006 as close conformity, below
+    /// 0.012 acceptable, below 0.015 marginal, and above as
+    /// nonconformity.
+    pub mad: f64,
+}
+
+/// Leading decimal digit of a finite, nonzero value.
+fn leading_digit(x: f64) -> usize {
+    // Scientific notation starts with the leading digit,
+    // avoiding rounding trouble with logarithms.
+    let text = format!("{:e}", x.abs());
+    text.as_bytes()[0] as usize - b'0' as usize
+}
+
+/// First-digit Benford analysis of input values. Zero and
+/// non-finite values have no leading digit and are left
+/// out; signs are ignored. The analysis is undefined if no
+/// values remain.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, benford_analysis(&[0.0, f64::NAN]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = benford_analysis(&[1.5, -0.017, 230.0, 0.0, 1e9]).unwrap();
+/// assert_eq!(4, r.count);
+/// assert_eq!([0.75, 0.25, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], r.observed);
+/// assert!((r.expected[0] - 2f64.log10()).abs() < 1e-15);
+/// assert_eq!(None, r.chi_square);
+/// ```
+/// ```
+/// # use stats::*;
+/// // Powers of two follow Benford's law closely.
+/// let powers: Vec<f64> = (0..1000).map(|i| 2f64.powi(i)).collect();
+/// let r = benford_analysis(&powers).unwrap();
+/// assert!(r.mad < 0.006);
+/// assert!(r.chi_square.unwrap().p_value > 0.5);
+/// // Uniform values over [1, 10) do not.
+/// let uniform: Vec<f64> = (0..900).map(|i| 1.0 + i as f64 / 100.0).collect();
+/// let r = benford_analysis(&uniform).unwrap();
+/// assert!(r.mad > 0.015);
+/// assert!(r.chi_square.unwrap().p_value < 1e-6);
+/// ```
+pub fn benford_analysis(nums: &[f64]) -> Option<BenfordAnalysis> {
+    let mut counts = [0usize; 9];
+    for &x in nums {
+        if x.is_finite() && x != 0.0 {
+            counts[leading_digit(x) - 1] += 1;
+        }
+    }
+    let count = counts.iter().sum::<usize>();
+    if count == 0 {
+        return None;
+    }
+    let n = count as f64;
+    let mut observed = [0.0; 9];
+    let mut expected = [0.0; 9];
+    for d in 0..9 {
+        observed[d] = counts[d] as f64 / n;
+        expected[d] = (1.0 + 1.0 / (d + 1) as f64).log10();
+    }
+    let observed_counts: Vec<f64> = counts.iter().map(|&c| c as f64).collect();
+    let expected_counts: Vec<f64> = expected.iter().map(|e| e * n).collect();
+    let mad = observed
+        .iter()
+        .zip(&expected)
+        .map(|(o, e)| (o - e).abs())
+        .sum::<f64>()
+        / 9.0;
+    Some(BenfordAnalysis {
+        count,
+        observed,
+        expected,
+        chi_square: chi_square_gof(&observed_counts, &expected_counts),
+        mad,
+    })
+}
@@ -0,0 +1,146 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of categorical data.
+
+/// Two-way table of counts of paired categorical
+/// observations. Row and column labels are kept in
+/// ascending order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContingencyTable<R, C> {
+    /// Distinct row labels.
+    pub rows: Vec<R>,
+    /// Distinct column labels.
+    pub cols: Vec<C>,
+    /// `observed[i][j]` is the number of observations with
+    /// row label `rows[i]` and column label `cols[j]`.
+    pub observed: Vec<Vec<usize>>,
+}
+
+/// Sorted distinct copy of the labels.
+fn levels<T: Ord + Clone>(labels: &[T]) -> Vec<T> {
+    let mut levels = labels.to_owned();
+    levels.sort();
+    levels.dedup();
+    levels
+}
+
+impl<R: Ord + Clone, C: Ord + Clone> ContingencyTable<R, C> {
+    /// Cross-tabulation of paired observations `(a[i],
+    /// b[i])`, with `a` giving the row and `b` the column.
+    /// The table is undefined for empty or unequal-length
+    /// inputs.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, ContingencyTable::new(&["x"], &[1, 2]));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let t = ContingencyTable::new(&["b", "a", "a", "b", "a"], &[1, 1, 2, 1, 1]).unwrap();
+    /// assert_eq!(vec!["a", "b"], t.rows);
+    /// assert_eq!(vec![1, 2], t.cols);
+    /// assert_eq!(vec![vec![2, 1], vec![2, 0]], t.observed);
+    /// ```
+    pub fn new(a: &[R], b: &[C]) -> Option<ContingencyTable<R, C>> {
+        if a.is_empty() || a.len() != b.len() {
+            return None;
+        }
+        let rows = levels(a);
+        let cols = levels(b);
+        let mut observed = vec![vec![0; cols.len()]; rows.len()];
+        for (r, c) in a.iter().zip(b) {
+            let i = rows.binary_search(r).unwrap();
+            let j = cols.binary_search(c).unwrap();
+            observed[i][j] += 1;
+        }
+        Some(ContingencyTable {
+            rows,
+            cols,
+            observed,
+        })
+    }
+}
+
+impl ContingencyTable<usize, usize> {
+    /// Table of already-tabulated counts, labeled by row
+    /// and column index. The table is undefined unless the
+    /// counts are non-empty and rectangular.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, ContingencyTable::from_counts(vec![vec![1, 2], vec![3]]));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let t = ContingencyTable::from_counts(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(vec![0, 1], t.cols);
+    /// ```
+    pub fn from_counts(observed: Vec<Vec<usize>>) -> Option<ContingencyTable<usize, usize>> {
+        let ncols = observed.first()?.len();
+        if ncols == 0 || observed.iter().any(|row| row.len() != ncols) {
+            return None;
+        }
+        Some(ContingencyTable {
+            rows: (0..observed.len()).collect(),
+            cols: (0..ncols).collect(),
+            observed,
+        })
+    }
+}
+
+impl<R, C> ContingencyTable<R, C> {
+    /// Total count of each row.
+    pub fn row_totals(&self) -> Vec<usize> {
+        self.observed.iter().map(|row| row.iter().sum()).collect()
+    }
+
+    /// Total count of each column.
+    pub fn col_totals(&self) -> Vec<usize> {
+        let mut totals = vec![0; self.cols.len()];
+        for row in &self.observed {
+            for (t, &c) in totals.iter_mut().zip(row) {
+                *t += c;
+            }
+        }
+        totals
+    }
+
+    /// Total count of the table.
+    pub fn total(&self) -> usize {
+        self.row_totals().iter().sum()
+    }
+
+    /// Counts expected in each cell if rows and columns were
+    /// independent: row total times column total divided by
+    /// the grand total.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let t = ContingencyTable::from_counts(vec![vec![1, 3], vec![3, 1]]).unwrap();
+    /// assert_eq!(vec![4, 4], t.row_totals());
+    /// assert_eq!(vec![4, 4], t.col_totals());
+    /// assert_eq!(vec![vec![2.0, 2.0], vec![2.0, 2.0]], t.expected());
+    /// ```
+    pub fn expected(&self) -> Vec<Vec<f64>> {
+        let total = self.total().max(1) as f64;
+        let col_totals = self.col_totals();
+        self.row_totals()
+            .iter()
+            .map(|&r| {
+                col_totals
+                    .iter()
+                    .map(|&c| r as f64 * c as f64 / total)
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of Arrow arrays and Parquet columns. Null
+//! entries are left out, as if absent from the data.
+
+use crate::{StatsError, Summary};
+use arrow_array::{Array, Float64Array};
+use parquet::basic::Type;
+use parquet::column::reader::ColumnReader;
+use parquet::file::reader::{ChunkReader, FileReader, SerializedFileReader};
+
+/// Number of values decoded at a time from a Parquet
+/// column.
+const PARQUET_BATCH: usize = 4096;
+
+/// Summary of the non-null entries of an array.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::columnar::*;
+/// use arrow_array::Float64Array;
+/// let a = Float64Array::from(vec![Some(1.0), None, Some(3.0)]);
+/// let s = summarize_array(&a);
+/// assert_eq!(2, s.count());
+/// assert_eq!(Some(2.0), s.mean());
+/// ```
+pub fn summarize_array(array: &Float64Array) -> Summary {
+    let mut summary = Summary::new();
+    for v in array.iter().flatten() {
+        summary.push(v);
+    }
+    summary
+}
+
+/// Value of `statistic` on the non-null entries of an
+/// array, copying them only if there are nulls. Any
+/// `StatFn` can serve as the statistic.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::columnar::*;
+/// use arrow_array::Float64Array;
+/// let a = Float64Array::from(vec![Some(4.0), None, Some(1.0), Some(2.0)]);
+/// assert_eq!(Some(2.0), apply_array(&a, stats::median));
+/// let b = Float64Array::from(vec![4.0, 1.0, 3.0, 2.0]);
+/// assert_eq!(Some(2.5), apply_array(&b, |x| stats::quantile(x, 0.5)));
+/// ```
+pub fn apply_array<F>(array: &Float64Array, statistic: F) -> Option<f64>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    if array.null_count() == 0 {
+        statistic(array.values())
+    } else {
+        statistic(&array.iter().flatten().collect::<Vec<f64>>())
+    }
+}
+
+/// Summary of the non-null values of the named top-level
+/// `DOUBLE` column of Parquet data, decoded a batch at a time
+/// rather than read into memory whole.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::columnar::*;
+/// # use parquet::data_type::DoubleType;
+/// # use parquet::file::writer::SerializedFileWriter;
+/// # use parquet::schema::parser::parse_message_type;
+/// # use std::sync::Arc;
+/// # let path = std::env::temp_dir().join("stats-columnar-doctest.parquet");
+/// # let schema = "message m { optional double x; required int32 n; }";
+/// # let schema = Arc::new(parse_message_type(schema).unwrap());
+/// # let file = std::fs::File::create(&path).unwrap();
+/// # let mut writer = SerializedFileWriter::new(file, schema, Default::default()).unwrap();
+/// # let mut group = writer.next_row_group().unwrap();
+/// # let mut column = group.next_column().unwrap().unwrap();
+/// # let values = column.typed::<DoubleType>();
+/// # values.write_batch(&[1.0, 2.0, 6.0], Some(&[1, 1, 0, 1]), None).unwrap();
+/// # column.close().unwrap();
+/// # let mut column = group.next_column().unwrap().unwrap();
+/// # let n = column.typed::<parquet::data_type::Int32Type>();
+/// # n.write_batch(&[0, 0, 0, 0], None, None).unwrap();
+/// # column.close().unwrap();
+/// # group.close().unwrap();
+/// # writer.close().unwrap();
+/// // A file whose column `x` holds 1, 2, null, 6.
+/// let file = std::fs::File::open(&path).unwrap();
+/// let s = summarize_parquet_column(file, "x").unwrap();
+/// assert_eq!(3, s.count());
+/// assert_eq!(Some(3.0), s.mean());
+/// ```
+/// ```
+/// # use stats::columnar::*;
+/// # use parquet::file::writer::SerializedFileWriter;
+/// # use parquet::schema::parser::parse_message_type;
+/// # use std::sync::Arc;
+/// # let path = std::env::temp_dir().join("stats-columnar-doctest-empty.parquet");
+/// # let schema = "message m { optional double x; required int32 n; }";
+/// # let schema = Arc::new(parse_message_type(schema).unwrap());
+/// # let file = std::fs::File::create(&path).unwrap();
+/// # SerializedFileWriter::new(file, schema, Default::default()).unwrap().close().unwrap();
+/// use stats::StatsError;
+/// let open = || std::fs::File::open(&path).unwrap();
+/// assert_eq!(0, summarize_parquet_column(open(), "x").unwrap().count());
+/// assert!(matches!(summarize_parquet_column(open(), "y"), Err(StatsError::MissingColumn(_))));
+/// assert!(matches!(summarize_parquet_column(open(), "n"), Err(StatsError::ColumnType(_))));
+/// ```
+pub fn summarize_parquet_column<R: ChunkReader + 'static>(
+    reader: R,
+    column: &str,
+) -> Result<Summary, StatsError> {
+    let reader = SerializedFileReader::new(reader)?;
+    let schema = reader.metadata().file_metadata().schema_descr();
+    let index = (0..schema.num_columns())
+        .find(|&i| schema.column(i).path().string() == column)
+        .ok_or_else(|| StatsError::MissingColumn(column.to_string()))?;
+    if schema.column(index).physical_type() != Type::DOUBLE {
+        return Err(StatsError::ColumnType(column.to_string()));
+    }
+    let mut summary = Summary::new();
+    let mut levels = Vec::new();
+    let mut values = Vec::new();
+    for group in 0..reader.num_row_groups() {
+        let mut column_reader = match reader.get_row_group(group)?.get_column_reader(index)? {
+            ColumnReader::DoubleColumnReader(r) => r,
+            _ => return Err(StatsError::ColumnType(column.to_string())),
+        };
+        loop {
+            let (records, _, _) =
+                column_reader.read_records(PARQUET_BATCH, Some(&mut levels), None, &mut values)?;
+            for v in values.drain(..) {
+                summary.push(v);
+            }
+            levels.clear();
+            if records == 0 {
+                break;
+            }
+        }
+    }
+    Ok(summary)
+}
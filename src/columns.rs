@@ -0,0 +1,95 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Column-wise statistics of tabular data, computed in a
+//! single pass over the data.
+
+use crate::Summary;
+use std::collections::BTreeMap;
+
+/// Summary of each column of input columns.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let s = summarize_columns(&[&[1.0, 2.0, 3.0], &[10.0, 30.0]]);
+/// assert_eq!(Some(2.0), s[0].mean());
+/// assert_eq!(Some(30.0), s[1].max());
+/// ```
+pub fn summarize_columns(columns: &[&[f64]]) -> Vec<Summary> {
+    columns.iter().map(|c| Summary::of(c)).collect()
+}
+
+/// Summary of each column of row-major data with
+/// `n_columns` values per row. The summaries are undefined
+/// for no columns, or when the data is not a whole number
+/// of rows.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, summarize_rows(&[1.0, 2.0, 3.0], 2));
+/// ```
+/// ```
+/// # use stats::*;
+/// let rows = [
+///     1.0, 10.0,
+///     2.0, 20.0,
+///     3.0, 60.0,
+/// ];
+/// let s = summarize_rows(&rows, 2).unwrap();
+/// assert_eq!(3, s[1].count());
+/// assert_eq!((Some(2.0), Some(30.0)), (s[0].mean(), s[1].mean()));
+/// assert_eq!(stddev(&[10.0, 20.0, 60.0]), s[1].stddev());
+/// ```
+pub fn summarize_rows(data: &[f64], n_columns: usize) -> Option<Vec<Summary>> {
+    if n_columns == 0 || !data.len().is_multiple_of(n_columns) {
+        return None;
+    }
+    let mut summaries = vec![Summary::new(); n_columns];
+    for row in data.chunks(n_columns) {
+        for (summary, &x) in summaries.iter_mut().zip(row) {
+            summary.push(x);
+        }
+    }
+    Some(summaries)
+}
+
+/// Summary of the values for each distinct key, where
+/// `keys[i]` is the key of `values[i]`. The summaries are
+/// undefined for inputs of unequal length.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, group_stats(&[1.0], &[1, 2]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let latency = [120.0, 80.0, 100.0, 90.0, 300.0];
+/// let region = ["eu", "us", "eu", "us", "ap"];
+/// let groups = group_stats(&latency, &region).unwrap();
+/// assert_eq!(vec!["ap", "eu", "us"], groups.keys().cloned().collect::<Vec<_>>());
+/// assert_eq!(Some(110.0), groups["eu"].mean());
+/// assert_eq!(2, groups["us"].count());
+/// ```
+pub fn group_stats<K: Ord + Clone>(values: &[f64], keys: &[K]) -> Option<BTreeMap<K, Summary>> {
+    if values.len() != keys.len() {
+        return None;
+    }
+    let mut groups: BTreeMap<K, Summary> = BTreeMap::new();
+    for (&x, key) in values.iter().zip(keys) {
+        match groups.get_mut(key) {
+            Some(summary) => summary.push(x),
+            None => {
+                groups.insert(key.clone(), Summary::of(&[x]));
+            }
+        }
+    }
+    Some(groups)
+}
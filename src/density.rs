@@ -0,0 +1,148 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Kernel density estimation.
+
+use crate::{quantile, sample_variance};
+
+/// Number of grid points at which `kde` evaluates the
+/// density.
+pub const KDE_GRID_POINTS: usize = 256;
+
+/// Smoothing kernel for density estimation. Each kernel
+/// integrates to 1 and is scaled by the bandwidth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kernel {
+    /// Standard normal density.
+    Gaussian,
+    /// Parabolic kernel `3/4 (1 - u²)` on `[-1, 1]`.
+    Epanechnikov,
+}
+
+impl Kernel {
+    /// Value of the unscaled kernel at `u`.
+    pub fn eval(self, u: f64) -> f64 {
+        match self {
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2.0 * std::f64::consts::PI).sqrt(),
+            Kernel::Epanechnikov if u.abs() <= 1.0 => 0.75 * (1.0 - u * u),
+            Kernel::Epanechnikov => 0.0,
+        }
+    }
+}
+
+/// Bandwidth selection for density estimation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bandwidth {
+    /// Silverman's rule of thumb:
+    /// `0.9 min(s, IQR / 1.34) n^(-1/5)`.
+    Silverman,
+    /// Scott's rule: `1.06 s n^(-1/5)`.
+    Scott,
+    /// A fixed, positive bandwidth.
+    Fixed(f64),
+}
+
+impl Bandwidth {
+    /// Bandwidth to use for the given input values. The
+    /// automatic rules are undefined for fewer than two
+    /// values or for constant input.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Bandwidth::Scott.select(&[1.0]));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(Some(0.5), Bandwidth::Fixed(0.5).select(&[1.0]));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let h = Bandwidth::Silverman.select(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+    /// assert!((h - 0.9 * 1.5 / 1.34 * 4.0f64.powf(-0.2)).abs() < 1e-12);
+    /// ```
+    pub fn select(self, nums: &[f64]) -> Option<f64> {
+        let h = match self {
+            Bandwidth::Fixed(h) => h,
+            Bandwidth::Scott | Bandwidth::Silverman => {
+                let s = sample_variance(nums)?.sqrt();
+                let n = (nums.len() as f64).powf(-0.2);
+                if self == Bandwidth::Scott {
+                    1.06 * s * n
+                } else {
+                    let iqr = quantile(nums, 0.75)? - quantile(nums, 0.25)?;
+                    let spread = if iqr > 0.0 { s.min(iqr / 1.34) } else { s };
+                    0.9 * spread * n
+                }
+            }
+        };
+        if h > 0.0 && h.is_finite() {
+            Some(h)
+        } else {
+            None
+        }
+    }
+}
+
+/// Kernel density estimate of input values, evaluated at
+/// each of the points `xs`. The estimate is undefined for
+/// an empty list or when no bandwidth can be selected.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, kde_at(&[], Bandwidth::Fixed(1.0), Kernel::Gaussian, &[0.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let d = kde_at(&[0.0], Bandwidth::Fixed(1.0), Kernel::Epanechnikov, &[0.0, 0.5, 2.0]);
+/// assert_eq!(Some(vec![0.75, 0.5625, 0.0]), d);
+/// ```
+pub fn kde_at(nums: &[f64], bandwidth: Bandwidth, kernel: Kernel, xs: &[f64]) -> Option<Vec<f64>> {
+    if nums.is_empty() {
+        return None;
+    }
+    let h = bandwidth.select(nums)?;
+    let scale = 1.0 / (nums.len() as f64 * h);
+    let density = xs
+        .iter()
+        .map(|x| scale * nums.iter().map(|v| kernel.eval((x - v) / h)).sum::<f64>())
+        .collect();
+    Some(density)
+}
+
+/// Kernel density estimate of input values, evaluated on
+/// an evenly-spaced grid of `KDE_GRID_POINTS` points
+/// extending three bandwidths past the data on either
+/// side. Returns `(x, density)` pairs.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, kde(&[], Bandwidth::Scott, Kernel::Gaussian));
+/// ```
+/// ```
+/// # use stats::*;
+/// let d = kde(&[1.0, 2.0, 2.5, 4.0], Bandwidth::Silverman, Kernel::Gaussian).unwrap();
+/// assert_eq!(KDE_GRID_POINTS, d.len());
+/// let dx = d[1].0 - d[0].0;
+/// let area: f64 = d.iter().map(|&(_, y)| y * dx).sum();
+/// assert!((area - 1.0).abs() < 0.01);
+/// ```
+pub fn kde(nums: &[f64], bandwidth: Bandwidth, kernel: Kernel) -> Option<Vec<(f64, f64)>> {
+    if nums.is_empty() {
+        return None;
+    }
+    let h = bandwidth.select(nums)?;
+    let lo = nums.iter().cloned().fold(f64::INFINITY, f64::min) - 3.0 * h;
+    let hi = nums.iter().cloned().fold(f64::NEG_INFINITY, f64::max) + 3.0 * h;
+    let step = (hi - lo) / (KDE_GRID_POINTS - 1) as f64;
+    let xs: Vec<f64> = (0..KDE_GRID_POINTS).map(|i| lo + i as f64 * step).collect();
+    let density = kde_at(nums, Bandwidth::Fixed(h), kernel, &xs)?;
+    Some(xs.into_iter().zip(density).collect())
+}
@@ -0,0 +1,970 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Probability distributions.
+
+use crate::special::{
+    beta_inc, gamma_p, gamma_q, invert_cdf, ln_gamma, normal_cdf, normal_quantile, student_t_cdf,
+    student_t_quantile,
+};
+use std::f64::consts::PI;
+
+/// A continuous probability distribution on the real line.
+/// Distributions on the non-negative reals have zero density
+/// below zero.
+pub trait Continuous {
+    /// Probability density at `x`.
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Probability of a value at or below `x`.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Quantile function: the value at or below which the
+    /// probability is `p`. NaN for `p` outside `[0, 1]`.
+    fn inverse_cdf(&self, p: f64) -> f64;
+
+    /// One random draw from the distribution, by inverting
+    /// the cdf at a uniform value strictly between 0 and 1.
+    #[cfg(feature = "rand")]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> f64
+    where
+        Self: Sized,
+    {
+        let u = ((rng.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        self.inverse_cdf(u)
+    }
+
+    /// `n` random draws from the distribution, from a
+    /// generator seeded with `seed`: the same seed always
+    /// gives the same draws.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// let n = Normal::new(10.0, 2.0).unwrap();
+    /// let draws = n.sample_n(10_000, 42);
+    /// assert_eq!(draws, n.sample_n(10_000, 42));
+    /// assert!((stats::mean(&draws).unwrap() - 10.0).abs() < 0.1);
+    /// assert!((stats::stddev(&draws).unwrap() - 2.0).abs() < 0.1);
+    /// ```
+    #[cfg(feature = "rand")]
+    fn sample_n(&self, n: usize, seed: u64) -> Vec<f64>
+    where
+        Self: Sized,
+    {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        (0..n).map(|_| self.sample(&mut rng)).collect()
+    }
+}
+
+/// Normal (Gaussian) distribution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Normal distribution with the given mean and standard
+    /// deviation. The distribution is undefined unless the
+    /// mean is finite and the standard deviation finite and
+    /// positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, Normal::new(0.0, 0.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let n = Normal::new(10.0, 2.0).unwrap();
+    /// assert_eq!(0.5, n.cdf(10.0));
+    /// assert!((n.cdf(12.0) - 0.841344746).abs() < 1e-9);
+    /// assert!((n.inverse_cdf(0.975) - (10.0 + 2.0 * 1.959963985)).abs() < 1e-8);
+    /// ```
+    pub fn new(mean: f64, std_dev: f64) -> Option<Normal> {
+        if mean.is_finite() && std_dev.is_finite() && std_dev > 0.0 {
+            Some(Normal { mean, std_dev })
+        } else {
+            None
+        }
+    }
+
+    /// Standard normal distribution: mean 0, standard
+    /// deviation 1.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// let z = Normal::standard();
+    /// assert!((z.pdf(0.0) - 0.398942280401433).abs() < 1e-15);
+    /// assert!((z.cdf(-1.959963984540054) - 0.025).abs() < 1e-15);
+    /// assert_eq!(0.0, z.inverse_cdf(0.5));
+    /// assert!(z.inverse_cdf(1.5).is_nan());
+    /// ```
+    pub fn standard() -> Normal {
+        Normal {
+            mean: 0.0,
+            std_dev: 1.0,
+        }
+    }
+
+    /// Mean of the distribution.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Standard deviation of the distribution.
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+}
+
+impl Continuous for Normal {
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std_dev;
+        (-0.5 * z * z).exp() / (self.std_dev * (2.0 * PI).sqrt())
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        normal_cdf((x - self.mean) / self.std_dev)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.mean + self.std_dev * normal_quantile(p)
+    }
+}
+
+/// Student's t distribution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    df: f64,
+}
+
+impl StudentT {
+    /// Student's t distribution with `df` degrees of
+    /// freedom, which need not be an integer. The
+    /// distribution is undefined unless `df` is finite and
+    /// positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, StudentT::new(-1.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let t = StudentT::new(10.0).unwrap();
+    /// assert!((t.inverse_cdf(0.975) - 2.228138852).abs() < 1e-9);
+    /// assert!((t.cdf(2.228138852) - 0.975).abs() < 1e-9);
+    /// assert!((t.pdf(0.0) - 0.389108383).abs() < 1e-9);
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let cauchy = StudentT::new(1.0).unwrap();
+    /// assert!((cauchy.cdf(1.0) - 0.75).abs() < 1e-12);
+    /// assert!((cauchy.pdf(1.0) - 0.5 / std::f64::consts::PI).abs() < 1e-12);
+    /// ```
+    pub fn new(df: f64) -> Option<StudentT> {
+        if df.is_finite() && df > 0.0 {
+            Some(StudentT { df })
+        } else {
+            None
+        }
+    }
+
+    /// Degrees of freedom of the distribution.
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+}
+
+impl Continuous for StudentT {
+    fn pdf(&self, x: f64) -> f64 {
+        let v = self.df;
+        let log_norm = ln_gamma(0.5 * (v + 1.0)) - ln_gamma(0.5 * v) - 0.5 * (v * PI).ln();
+        (log_norm - 0.5 * (v + 1.0) * (x * x / v).ln_1p()).exp()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        student_t_cdf(x, self.df)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        student_t_quantile(p, self.df)
+    }
+}
+
+/// Quantile of a distribution on the non-negative reals with
+/// cumulative distribution function `cdf`.
+fn positive_quantile<F: Fn(f64) -> f64>(cdf: F, p: f64) -> f64 {
+    if !(0.0..=1.0).contains(&p) {
+        f64::NAN
+    } else if p == 0.0 {
+        0.0
+    } else if p == 1.0 {
+        f64::INFINITY
+    } else {
+        invert_cdf(cdf, p, 0.0)
+    }
+}
+
+/// Chi-square distribution: the distribution of a sum of
+/// squares of independent standard normals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquare {
+    df: f64,
+}
+
+impl ChiSquare {
+    /// Chi-square distribution with `df` degrees of
+    /// freedom, which need not be an integer. The
+    /// distribution is undefined unless `df` is finite and
+    /// positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, ChiSquare::new(0.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let c = ChiSquare::new(1.0).unwrap();
+    /// assert!((c.inverse_cdf(0.95) - 3.841458821).abs() < 1e-9);
+    /// let c = ChiSquare::new(10.0).unwrap();
+    /// assert!((c.inverse_cdf(0.95) - 18.307038053).abs() < 1e-9);
+    /// assert_eq!(0.0, c.cdf(-1.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let c = ChiSquare::new(2.0).unwrap();
+    /// assert!((c.cdf(3.0) - (1.0 - (-1.5f64).exp())).abs() < 1e-15);
+    /// assert!((c.pdf(3.0) - 0.5 * (-1.5f64).exp()).abs() < 1e-15);
+    /// ```
+    pub fn new(df: f64) -> Option<ChiSquare> {
+        if df.is_finite() && df > 0.0 {
+            Some(ChiSquare { df })
+        } else {
+            None
+        }
+    }
+
+    /// Degrees of freedom of the distribution.
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+}
+
+impl Continuous for ChiSquare {
+    fn pdf(&self, x: f64) -> f64 {
+        let k = 0.5 * self.df;
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if k < 1.0 {
+                f64::INFINITY
+            } else if k == 1.0 {
+                0.5
+            } else {
+                0.0
+            }
+        } else {
+            ((k - 1.0) * x.ln() - 0.5 * x - k * 2f64.ln() - ln_gamma(k)).exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        gamma_p(0.5 * self.df, 0.5 * x.max(0.0))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        positive_quantile(|x| self.cdf(x), p)
+    }
+}
+
+/// Fisher's F distribution: the distribution of a ratio of
+/// independent chi-square variables, each divided by its
+/// degrees of freedom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FisherF {
+    df1: f64,
+    df2: f64,
+}
+
+impl FisherF {
+    /// F distribution with `df1` numerator and `df2`
+    /// denominator degrees of freedom. The distribution is
+    /// undefined unless both are finite and positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, FisherF::new(1.0, f64::NAN));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let f = FisherF::new(3.0, 10.0).unwrap();
+    /// assert!((f.inverse_cdf(0.95) - 3.708264819).abs() < 1e-8);
+    /// assert!((f.cdf(3.708264819) - 0.95).abs() < 1e-9);
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// // F(1, ν) is the square of a t with ν degrees of freedom.
+    /// let (f, t) = (FisherF::new(1.0, 6.0).unwrap(), StudentT::new(6.0).unwrap());
+    /// assert!((f.cdf(4.0) - (2.0 * t.cdf(2.0) - 1.0)).abs() < 1e-12);
+    /// assert!((f.pdf(4.0) - t.pdf(2.0) / 2.0).abs() < 1e-12);
+    /// ```
+    pub fn new(df1: f64, df2: f64) -> Option<FisherF> {
+        if df1.is_finite() && df1 > 0.0 && df2.is_finite() && df2 > 0.0 {
+            Some(FisherF { df1, df2 })
+        } else {
+            None
+        }
+    }
+
+    /// Numerator degrees of freedom.
+    pub fn df1(&self) -> f64 {
+        self.df1
+    }
+
+    /// Denominator degrees of freedom.
+    pub fn df2(&self) -> f64 {
+        self.df2
+    }
+}
+
+impl Continuous for FisherF {
+    fn pdf(&self, x: f64) -> f64 {
+        let (a, b) = (0.5 * self.df1, 0.5 * self.df2);
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return if a < 1.0 {
+                f64::INFINITY
+            } else if a == 1.0 {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        let log_beta = ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b);
+        let log_density = a * (self.df1 / self.df2).ln() + (a - 1.0) * x.ln()
+            - (a + b) * (self.df1 * x / self.df2).ln_1p()
+            - log_beta;
+        log_density.exp()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let y = self.df1 * x;
+        beta_inc(0.5 * self.df1, 0.5 * self.df2, y / (y + self.df2))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        positive_quantile(|x| self.cdf(x), p)
+    }
+}
+
+/// Exponential distribution: waiting times between events
+/// of a Poisson process.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+    rate: f64,
+}
+
+impl Exponential {
+    /// Exponential distribution with the given rate, the
+    /// reciprocal of its mean. The distribution is undefined
+    /// unless the rate is finite and positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, Exponential::new(0.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let e = Exponential::new(2.0).unwrap();
+    /// assert_eq!(2.0, e.pdf(0.0));
+    /// assert!((e.cdf(1.0) - (1.0 - (-2.0f64).exp())).abs() < 1e-15);
+    /// assert!((e.inverse_cdf(0.5) - 2f64.ln() / 2.0).abs() < 1e-15);
+    /// ```
+    pub fn new(rate: f64) -> Option<Exponential> {
+        if rate.is_finite() && rate > 0.0 {
+            Some(Exponential { rate })
+        } else {
+            None
+        }
+    }
+
+    /// Rate of the distribution.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+impl Continuous for Exponential {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.rate * (-self.rate * x).exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        -(-self.rate * x.max(0.0)).exp_m1()
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            f64::NAN
+        } else {
+            -(-p).ln_1p() / self.rate
+        }
+    }
+}
+
+/// Log-normal distribution: the distribution of `e^X` for
+/// normally distributed `X`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNormal {
+    normal: Normal,
+}
+
+impl LogNormal {
+    /// Log-normal distribution whose logarithm has mean `mu`
+    /// and standard deviation `sigma`. The distribution is
+    /// undefined when that normal distribution is.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, LogNormal::new(0.0, -1.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let l = LogNormal::new(1.0, 0.5).unwrap();
+    /// assert!((l.cdf(1f64.exp()) - 0.5).abs() < 1e-15);
+    /// assert!((l.inverse_cdf(0.5) - 1f64.exp()).abs() < 1e-14);
+    /// assert_eq!(0.0, l.pdf(-1.0));
+    /// ```
+    pub fn new(mu: f64, sigma: f64) -> Option<LogNormal> {
+        Normal::new(mu, sigma).map(|normal| LogNormal { normal })
+    }
+
+    /// Mean of the logarithm of the distribution.
+    pub fn mu(&self) -> f64 {
+        self.normal.mean
+    }
+
+    /// Standard deviation of the logarithm of the
+    /// distribution.
+    pub fn sigma(&self) -> f64 {
+        self.normal.std_dev
+    }
+}
+
+impl Continuous for LogNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.normal.pdf(x.ln()) / x
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.normal.cdf(x.ln())
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.normal.inverse_cdf(p).exp()
+    }
+}
+
+/// Maximum-likelihood fit of a distribution to a sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit<D> {
+    /// The fitted distribution.
+    pub distribution: D,
+    /// Asymptotic standard errors of the estimated
+    /// parameters, in the order the distribution's
+    /// constructor takes them.
+    pub std_errors: Vec<f64>,
+    /// Log-likelihood of the sample under the fit.
+    pub log_likelihood: f64,
+    /// Akaike information criterion `2k - 2 ln L` for `k`
+    /// parameters. Smaller is better when comparing fits of
+    /// the same sample.
+    pub aic: f64,
+}
+
+impl<D> Fit<D> {
+    fn new(distribution: D, std_errors: Vec<f64>, log_likelihood: f64) -> Fit<D> {
+        let aic = 2.0 * std_errors.len() as f64 - 2.0 * log_likelihood;
+        Fit {
+            distribution,
+            std_errors,
+            log_likelihood,
+            aic,
+        }
+    }
+}
+
+/// Fit a normal distribution by maximum likelihood: the
+/// sample mean and the population standard deviation. The
+/// fit is undefined for an empty or constant sample.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::dist::*;
+/// assert_eq!(None, fit_normal(&[3.0, 3.0]));
+/// ```
+/// ```
+/// # use stats::dist::*;
+/// let fit = fit_normal(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+/// assert_eq!(3.0, fit.distribution.mean());
+/// assert!((fit.distribution.std_dev() - 2f64.sqrt()).abs() < 1e-15);
+/// assert!((fit.std_errors[0] - 0.4f64.sqrt()).abs() < 1e-15);
+/// assert!((fit.std_errors[1] - 0.2f64.sqrt()).abs() < 1e-15);
+/// assert!((fit.log_likelihood - -8.827561).abs() < 1e-6);
+/// assert!((fit.aic - 21.655121).abs() < 1e-6);
+/// ```
+pub fn fit_normal(nums: &[f64]) -> Option<Fit<Normal>> {
+    if nums.is_empty() {
+        return None;
+    }
+    let n = nums.len() as f64;
+    let distribution = Normal::new(crate::mean(nums)?, crate::stddev(nums)?)?;
+    let s = distribution.std_dev;
+    let log_likelihood = -0.5 * n * ((2.0 * PI * s * s).ln() + 1.0);
+    let std_errors = vec![s / n.sqrt(), s / (2.0 * n).sqrt()];
+    Some(Fit::new(distribution, std_errors, log_likelihood))
+}
+
+/// Fit an exponential distribution by maximum likelihood:
+/// the reciprocal of the sample mean. The fit is undefined
+/// for an empty sample, for negative values, or for an
+/// all-zero sample.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::dist::*;
+/// assert_eq!(None, fit_exponential(&[1.0, -1.0]));
+/// ```
+/// ```
+/// # use stats::dist::*;
+/// let fit = fit_exponential(&[0.5, 1.0, 1.5, 1.0]).unwrap();
+/// assert_eq!(1.0, fit.distribution.rate());
+/// assert_eq!(vec![0.5], fit.std_errors);
+/// assert_eq!(-4.0, fit.log_likelihood);
+/// assert_eq!(10.0, fit.aic);
+/// ```
+pub fn fit_exponential(nums: &[f64]) -> Option<Fit<Exponential>> {
+    if nums.is_empty() || !nums.iter().all(|&x| x >= 0.0) {
+        return None;
+    }
+    let n = nums.len() as f64;
+    let distribution = Exponential::new(1.0 / crate::mean(nums)?)?;
+    let rate = distribution.rate;
+    let log_likelihood = n * rate.ln() - n;
+    Some(Fit::new(
+        distribution,
+        vec![rate / n.sqrt()],
+        log_likelihood,
+    ))
+}
+
+/// Fit a log-normal distribution by maximum likelihood: a
+/// normal fit to the logarithms of the values. The fit is
+/// undefined for an empty or constant sample, or for
+/// values that are not positive.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::dist::*;
+/// assert_eq!(None, fit_lognormal(&[1.0, 0.0]));
+/// ```
+/// ```
+/// # use stats::dist::*;
+/// let e = 1f64.exp();
+/// let fit = fit_lognormal(&[1.0 / e, e]).unwrap();
+/// assert!(fit.distribution.mu().abs() < 1e-15);
+/// assert!((fit.distribution.sigma() - 1.0).abs() < 1e-15);
+/// assert!((fit.log_likelihood - -2.837877).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::dist::*;
+/// // Compare candidate models for skewed data.
+/// let data = [0.3, 0.6, 0.9, 1.1, 1.4, 2.0, 2.9, 4.2, 7.5];
+/// let normal = fit_normal(&data).unwrap();
+/// let lognormal = fit_lognormal(&data).unwrap();
+/// assert!(lognormal.aic < normal.aic);
+/// ```
+pub fn fit_lognormal(nums: &[f64]) -> Option<Fit<LogNormal>> {
+    if !nums.iter().all(|&x| x > 0.0) {
+        return None;
+    }
+    let logs: Vec<f64> = nums.iter().map(|x| x.ln()).collect();
+    let fit = fit_normal(&logs)?;
+    let log_likelihood = fit.log_likelihood - logs.iter().sum::<f64>();
+    Some(Fit::new(
+        LogNormal {
+            normal: fit.distribution,
+        },
+        fit.std_errors,
+        log_likelihood,
+    ))
+}
+
+/// A discrete probability distribution on the non-negative
+/// integers.
+pub trait Discrete {
+    /// Probability of exactly `k`.
+    fn pmf(&self, k: u64) -> f64;
+
+    /// Probability of a value at or below `k`.
+    fn cdf(&self, k: u64) -> f64;
+
+    /// Quantile function: the smallest value at or below
+    /// which the probability is at least `p`. The quantile
+    /// is undefined for `p` outside `[0, 1]`, or when no
+    /// such value exists.
+    fn inverse_cdf(&self, p: f64) -> Option<u64>;
+}
+
+/// Smallest `k` no larger than `max` with `cdf(k) >= p`.
+fn discrete_quantile<F: Fn(u64) -> f64>(cdf: F, p: f64, max: u64) -> Option<u64> {
+    if !(0.0..=1.0).contains(&p) {
+        return None;
+    }
+    let mut hi = 1u64.min(max);
+    while cdf(hi) < p {
+        if hi == max {
+            return None;
+        }
+        hi = hi.saturating_mul(2).min(max);
+    }
+    let mut lo = 0;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if cdf(mid) < p {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(lo)
+}
+
+/// Binomial distribution: the number of successes in a
+/// fixed number of independent trials.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    trials: u64,
+    probability: f64,
+}
+
+impl Binomial {
+    /// Binomial distribution of `trials` trials, each
+    /// succeeding with the given probability. The
+    /// distribution is undefined unless the probability is
+    /// in `[0, 1]`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, Binomial::new(10, 1.5));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let b = Binomial::new(10, 0.5).unwrap();
+    /// assert!((b.pmf(5) - 252.0 / 1024.0).abs() < 1e-15);
+    /// assert!((b.cdf(5) - 638.0 / 1024.0).abs() < 1e-15);
+    /// assert_eq!(1.0, b.cdf(10));
+    /// assert_eq!(Some(5), b.inverse_cdf(0.5));
+    /// assert_eq!(Some(10), b.inverse_cdf(1.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let b = Binomial::new(3, 0.0).unwrap();
+    /// assert_eq!(1.0, b.pmf(0));
+    /// assert_eq!(Some(0), b.inverse_cdf(0.9));
+    /// ```
+    pub fn new(trials: u64, probability: f64) -> Option<Binomial> {
+        if (0.0..=1.0).contains(&probability) {
+            Some(Binomial {
+                trials,
+                probability,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Number of trials.
+    pub fn trials(&self) -> u64 {
+        self.trials
+    }
+
+    /// Probability of success in each trial.
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+}
+
+impl Discrete for Binomial {
+    fn pmf(&self, k: u64) -> f64 {
+        let (n, p) = (self.trials, self.probability);
+        if k > n {
+            0.0
+        } else if p == 0.0 || p == 1.0 {
+            let certain = if p == 0.0 { 0 } else { n };
+            if k == certain {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            let (n, k) = (n as f64, k as f64);
+            let log_choose = ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0);
+            (log_choose + k * p.ln() + (n - k) * (-p).ln_1p()).exp()
+        }
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        if k >= self.trials {
+            1.0
+        } else {
+            let (n, k) = (self.trials as f64, k as f64);
+            beta_inc(n - k, k + 1.0, 1.0 - self.probability)
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Option<u64> {
+        discrete_quantile(|k| self.cdf(k), p, self.trials)
+    }
+}
+
+/// Poisson distribution: the number of events in a fixed
+/// interval when events occur independently at a constant
+/// rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Poisson distribution with mean `lambda`. The
+    /// distribution is undefined unless `lambda` is finite
+    /// and positive.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, Poisson::new(0.0));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let p = Poisson::new(3.0).unwrap();
+    /// assert!((p.pmf(2) - 4.5 * (-3.0f64).exp()).abs() < 1e-15);
+    /// assert!((p.cdf(2) - 8.5 * (-3.0f64).exp()).abs() < 1e-15);
+    /// assert_eq!(Some(3), p.inverse_cdf(0.5));
+    /// assert_eq!(None, p.inverse_cdf(1.0));
+    /// ```
+    pub fn new(lambda: f64) -> Option<Poisson> {
+        if lambda.is_finite() && lambda > 0.0 {
+            Some(Poisson { lambda })
+        } else {
+            None
+        }
+    }
+
+    /// Mean of the distribution.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Discrete for Poisson {
+    fn pmf(&self, k: u64) -> f64 {
+        let k = k as f64;
+        (k * self.lambda.ln() - self.lambda - ln_gamma(k + 1.0)).exp()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        gamma_q(k as f64 + 1.0, self.lambda)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Option<u64> {
+        // The cdf approaches 1 but never reaches it.
+        if p == 1.0 {
+            None
+        } else {
+            discrete_quantile(|k| self.cdf(k), p, u64::MAX)
+        }
+    }
+}
+
+/// How `EmpiricalDist` treats values between the observed
+/// points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Only observed values occur: the cdf is the step
+    /// function of the sample.
+    Step,
+    /// The cdf rises linearly between consecutive observed
+    /// values, matching the crate's `quantile`.
+    Linear,
+}
+
+/// Distribution given by a sample, for simulation without
+/// assuming a parametric form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmpiricalDist {
+    values: Vec<f64>,
+    interpolation: Interpolation,
+}
+
+impl EmpiricalDist {
+    /// Empirical distribution of input values. The
+    /// distribution of an empty list is undefined.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// assert_eq!(None, EmpiricalDist::new(&[], Interpolation::Step));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let e = EmpiricalDist::new(&[30.0, 10.0, 20.0, 40.0], Interpolation::Step).unwrap();
+    /// assert_eq!(0.5, e.cdf(25.0));
+    /// assert_eq!(10.0, e.inverse_cdf(0.25));
+    /// assert_eq!(20.0, e.inverse_cdf(0.26));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let e = EmpiricalDist::new(&[30.0, 10.0, 20.0, 40.0], Interpolation::Linear).unwrap();
+    /// assert_eq!(0.5, e.cdf(25.0));
+    /// assert_eq!(25.0, e.inverse_cdf(0.5));
+    /// assert_eq!(0.0, e.cdf(5.0));
+    /// assert_eq!(1.0, e.cdf(40.0));
+    /// ```
+    pub fn new(nums: &[f64], interpolation: Interpolation) -> Option<EmpiricalDist> {
+        if nums.is_empty() {
+            return None;
+        }
+        Some(EmpiricalDist {
+            values: crate::sorted(nums),
+            interpolation,
+        })
+    }
+
+    /// Sample values in ascending order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Probability of a value at or below `x`.
+    pub fn cdf(&self, x: f64) -> f64 {
+        let n = self.values.len();
+        let at_or_below = self.values.partition_point(|&v| v <= x);
+        match self.interpolation {
+            Interpolation::Step => at_or_below as f64 / n as f64,
+            Interpolation::Linear => {
+                if at_or_below == 0 {
+                    0.0
+                } else if at_or_below == n {
+                    1.0
+                } else {
+                    let i = at_or_below - 1;
+                    let (lo, hi) = (self.values[i], self.values[i + 1]);
+                    (i as f64 + (x - lo) / (hi - lo)) / (n - 1) as f64
+                }
+            }
+        }
+    }
+
+    /// Quantile function: the value at or below which the
+    /// probability is `p`. NaN for `p` outside `[0, 1]`.
+    pub fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        match self.interpolation {
+            Interpolation::Step => {
+                let n = self.values.len();
+                let rank = (p * n as f64).ceil() as usize;
+                self.values[rank.clamp(1, n) - 1]
+            }
+            Interpolation::Linear => crate::sorted_quantile(&self.values, p),
+        }
+    }
+
+    /// `n` random draws from the distribution by inverse-cdf
+    /// sampling, from a generator seeded with `seed`: the
+    /// same seed always gives the same draws.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// let latencies = [12.0, 15.0, 11.0, 30.0, 14.0, 13.0];
+    /// let e = EmpiricalDist::new(&latencies, Interpolation::Step).unwrap();
+    /// let draws = e.sample_n(100, 7);
+    /// assert_eq!(draws, e.sample_n(100, 7));
+    /// assert!(draws.iter().all(|d| latencies.contains(d)));
+    /// ```
+    /// ```
+    /// # use stats::dist::*;
+    /// let e = EmpiricalDist::new(&[1.0, 2.0], Interpolation::Linear).unwrap();
+    /// assert!(e.sample_n(100, 7).iter().all(|&d| 1.0 < d && d < 2.0));
+    /// ```
+    pub fn sample_n(&self, n: usize, seed: u64) -> Vec<f64> {
+        let mut rng = crate::rng::Rng::new(seed);
+        (0..n).map(|_| self.inverse_cdf(rng.next_f64())).collect()
+    }
+
+    /// One random draw from the distribution, from a
+    /// caller-supplied generator.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::dist::*;
+    /// use rand::SeedableRng;
+    /// let e = EmpiricalDist::new(&[1.0, 2.0, 3.0], Interpolation::Step).unwrap();
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+    /// assert!([1.0, 2.0, 3.0].contains(&e.sample(&mut rng)));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u = ((rng.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        self.inverse_cdf(u)
+    }
+}
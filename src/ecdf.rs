@@ -0,0 +1,58 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Empirical cumulative distribution functions.
+
+use crate::sorted;
+
+/// Empirical cumulative distribution function of a sample:
+/// the fraction of sample values at or below a point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ecdf {
+    values: Vec<f64>,
+}
+
+impl Ecdf {
+    /// Empirical distribution of input values. The
+    /// distribution of an empty list is undefined.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Ecdf::new(&[]));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let e = Ecdf::new(&[3.0, 1.0, 2.0, 2.0]).unwrap();
+    /// assert_eq!(0.0, e.eval(0.5));
+    /// assert_eq!(0.75, e.eval(2.0));
+    /// assert_eq!(1.0, e.eval(10.0));
+    /// ```
+    pub fn new(nums: &[f64]) -> Option<Ecdf> {
+        if nums.is_empty() {
+            return None;
+        }
+        Some(Ecdf {
+            values: sorted(nums),
+        })
+    }
+
+    /// Fraction of sample values less than or equal to `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        self.values.partition_point(|&v| v <= x) as f64 / self.len() as f64
+    }
+
+    /// Number of sample values.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Sample values in ascending order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+}
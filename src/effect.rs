@@ -0,0 +1,123 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Effect sizes: how large a difference between two
+//! samples is, as opposed to whether it is significant.
+
+use crate::special::ln_gamma;
+use crate::{mean, sample_variance, sorted};
+
+/// Standard deviation by which a difference in means is
+/// scaled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Standardizer {
+    /// Pooled standard deviation of both samples.
+    Pooled,
+    /// Standard deviation of the second (control) sample
+    /// only: Glass's delta.
+    Glass,
+}
+
+/// Standardized difference in means, along with the degrees
+/// of freedom of the standardizer.
+fn standardized_difference(a: &[f64], b: &[f64], by: Standardizer) -> Option<(f64, f64)> {
+    let (va, vb) = (sample_variance(a)?, sample_variance(b)?);
+    let (na, nb) = (a.len() as f64, b.len() as f64);
+    let (variance, df) = match by {
+        Standardizer::Pooled => {
+            let df = na + nb - 2.0;
+            (((na - 1.0) * va + (nb - 1.0) * vb) / df, df)
+        }
+        Standardizer::Glass => (vb, nb - 1.0),
+    };
+    if variance == 0.0 {
+        return None;
+    }
+    Some(((mean(a)? - mean(b)?) / variance.sqrt(), df))
+}
+
+/// Cohen's d: the difference in means of `a` and `b` in
+/// units of the chosen standard deviation. Positive when
+/// `a` has the larger mean. The effect size is undefined
+/// unless each sample has at least two values and the
+/// standardizer is non-zero.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, cohens_d(&[1.0], &[1.0, 2.0], Standardizer::Pooled));
+/// ```
+/// ```
+/// # use stats::*;
+/// let (a, b) = ([1.0, 2.0, 3.0, 4.0, 5.0], [3.0, 4.0, 5.0, 6.0, 7.0]);
+/// let d = cohens_d(&a, &b, Standardizer::Pooled).unwrap();
+/// assert!((d + 2.0 / 2.5f64.sqrt()).abs() < 1e-12);
+/// ```
+/// ```
+/// # use stats::*;
+/// let d = cohens_d(&[2.0, 3.0, 4.0], &[0.0, 2.0], Standardizer::Glass).unwrap();
+/// assert!((d - 2.0 / 2.0f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn cohens_d(a: &[f64], b: &[f64], standardizer: Standardizer) -> Option<f64> {
+    Some(standardized_difference(a, b, standardizer)?.0)
+}
+
+/// Hedges' g: Cohen's d multiplied by the exact small-sample
+/// bias correction `J(ν) = Γ(ν/2) / (√(ν/2) Γ((ν-1)/2))`,
+/// where `ν` is the degrees of freedom of the standardizer.
+/// Undefined where Cohen's d is.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let (a, b) = ([1.0, 2.0, 3.0, 4.0, 5.0], [3.0, 4.0, 5.0, 6.0, 7.0]);
+/// let g = hedges_g(&a, &b, Standardizer::Pooled).unwrap();
+/// assert!((g + 1.141839).abs() < 1e-6);
+/// ```
+pub fn hedges_g(a: &[f64], b: &[f64], standardizer: Standardizer) -> Option<f64> {
+    let (d, df) = standardized_difference(a, b, standardizer)?;
+    let j = (ln_gamma(df / 2.0) - ln_gamma((df - 1.0) / 2.0)).exp() / (df / 2.0).sqrt();
+    Some(d * j)
+}
+
+/// Cliff's delta: the probability that a value from `a`
+/// exceeds one from `b`, minus the probability of the
+/// reverse, from -1 (every `a` below every `b`) to 1. The
+/// nonparametric counterpart of Cohen's d and a rescaling of
+/// the Mann–Whitney U. Computed in O(n log n) time by
+/// binary search. The effect size is undefined for an empty
+/// sample.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, cliffs_delta(&[], &[1.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(-1.0), cliffs_delta(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(-0.5), cliffs_delta(&[1.0, 2.0, 2.0, 7.0], &[2.0, 3.0, 8.0]));
+/// ```
+pub fn cliffs_delta(a: &[f64], b: &[f64]) -> Option<f64> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let b = sorted(b);
+    let dominance = a
+        .iter()
+        .map(|&x| {
+            let below = b.partition_point(|&y| y < x);
+            let above = b.len() - b.partition_point(|&y| y <= x);
+            below as f64 - above as f64
+        })
+        .sum::<f64>();
+    Some(dominance / (a.len() * b.len()) as f64)
+}
@@ -0,0 +1,114 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Errors for operations that can fail for reasons other
+//! than the statistic being undefined, such as reading
+//! input, and for the statistics in `v2`.
+
+use std::fmt;
+
+/// Failure reading, interpreting or computing on input
+/// data. Further kinds of failure may be added, and some
+/// depend on the enabled features, so matches need a
+/// wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StatsError {
+    /// Reading the input failed.
+    Io(std::io::Error),
+    /// The input is not valid CSV.
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+    /// The input is not valid Parquet.
+    #[cfg(feature = "arrow")]
+    Parquet(parquet::errors::ParquetError),
+    /// The requested column is not in the input.
+    MissingColumn(String),
+    /// The requested column does not hold numbers of the
+    /// expected type.
+    ColumnType(String),
+    /// A field on the given line is not a number.
+    Parse {
+        /// Line of the input, starting at 1.
+        line: u64,
+        /// The offending field.
+        field: String,
+    },
+    /// A required value on the given line is missing.
+    MissingValue {
+        /// Line of the input, starting at 1.
+        line: u64,
+    },
+    /// There are no input values.
+    EmptyInput,
+    /// An input value is NaN.
+    NanInput {
+        /// Position of the first NaN.
+        index: usize,
+    },
+    /// A parameter is outside its valid range.
+    OutOfRange {
+        /// Name of the parameter.
+        name: &'static str,
+        /// The offending value.
+        value: f64,
+    },
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatsError::Io(e) => write!(f, "read failed: {}", e),
+            #[cfg(feature = "csv")]
+            StatsError::Csv(e) => write!(f, "invalid CSV: {}", e),
+            #[cfg(feature = "arrow")]
+            StatsError::Parquet(e) => write!(f, "invalid Parquet: {}", e),
+            StatsError::MissingColumn(column) => write!(f, "no column {}", column),
+            StatsError::ColumnType(column) => write!(f, "column {} is not numeric", column),
+            StatsError::Parse { line, field } => {
+                write!(f, "line {}: {:?} is not a number", line, field)
+            }
+            StatsError::MissingValue { line } => write!(f, "line {}: missing value", line),
+            StatsError::EmptyInput => write!(f, "no input values"),
+            StatsError::NanInput { index } => write!(f, "input value {} is NaN", index),
+            StatsError::OutOfRange { name, value } => {
+                write!(f, "{} = {} is out of range", name, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StatsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StatsError::Io(e) => Some(e),
+            #[cfg(feature = "csv")]
+            StatsError::Csv(e) => Some(e),
+            #[cfg(feature = "arrow")]
+            StatsError::Parquet(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for StatsError {
+    fn from(e: std::io::Error) -> StatsError {
+        StatsError::Io(e)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for StatsError {
+    fn from(e: csv::Error) -> StatsError {
+        StatsError::Csv(e)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<parquet::errors::ParquetError> for StatsError {
+    fn from(e: parquet::errors::ParquetError) -> StatsError {
+        StatsError::Parquet(e)
+    }
+}
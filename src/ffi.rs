@@ -0,0 +1,352 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! C interface, declared in `include/stats.h`. Inputs are
+//! a pointer and a length; results are written through an
+//! out-parameter, and each function returns a status code.
+//! A running summary is an opaque handle from
+//! `stats_summary_new`, to be released with
+//! `stats_summary_free`.
+
+use crate::Summary;
+use std::os::raw::c_int;
+
+/// Status: the result was written.
+pub const STATS_OK: c_int = 0;
+/// Status: a required pointer was null.
+pub const STATS_NULL_POINTER: c_int = 1;
+/// Status: the statistic is undefined for the input, and
+/// nothing was written.
+pub const STATS_UNDEFINED: c_int = 2;
+
+/// The `len` values at `nums`, which may be null only if
+/// `len` is 0.
+unsafe fn values<'a>(nums: *const f64, len: usize) -> Option<&'a [f64]> {
+    if len == 0 {
+        Some(&[])
+    } else if nums.is_null() {
+        None
+    } else {
+        Some(std::slice::from_raw_parts(nums, len))
+    }
+}
+
+/// Write a result through `out`, returning the status.
+unsafe fn write(out: *mut f64, result: Option<f64>) -> c_int {
+    if out.is_null() {
+        return STATS_NULL_POINTER;
+    }
+    match result {
+        Some(x) => {
+            *out = x;
+            STATS_OK
+        }
+        None => STATS_UNDEFINED,
+    }
+}
+
+/// Apply a statistic to the `len` values at `nums`.
+unsafe fn apply<F: Fn(&[f64]) -> Option<f64>>(
+    nums: *const f64,
+    len: usize,
+    out: *mut f64,
+    statistic: F,
+) -> c_int {
+    match values(nums, len) {
+        Some(nums) => write(out, statistic(nums)),
+        None => STATS_NULL_POINTER,
+    }
+}
+
+/// Arithmetic mean, as for `mean`.
+///
+/// # Safety
+///
+/// `nums` must point to `len` readable values (or may be
+/// null if `len` is 0), and `out` to a writable value.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::ffi::*;
+/// let nums = [1.0, 2.0, 3.0];
+/// let mut out = 0.0;
+/// assert_eq!(STATS_OK, unsafe { stats_mean(nums.as_ptr(), 3, &mut out) });
+/// assert_eq!(2.0, out);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn stats_mean(nums: *const f64, len: usize, out: *mut f64) -> c_int {
+    apply(nums, len, out, crate::mean)
+}
+
+/// Population standard deviation, as for `stddev`.
+///
+/// # Safety
+///
+/// As for `stats_mean`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::ffi::*;
+/// let mut out = 0.0;
+/// assert_eq!(STATS_UNDEFINED, unsafe { stats_stddev(std::ptr::null(), 0, &mut out) });
+/// assert_eq!(STATS_NULL_POINTER, unsafe { stats_stddev(std::ptr::null(), 1, &mut out) });
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn stats_stddev(nums: *const f64, len: usize, out: *mut f64) -> c_int {
+    apply(nums, len, out, crate::stddev)
+}
+
+/// Median, as for `median`.
+///
+/// # Safety
+///
+/// As for `stats_mean`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_median(nums: *const f64, len: usize, out: *mut f64) -> c_int {
+    apply(nums, len, out, crate::median)
+}
+
+/// L2 norm, as for `l2`.
+///
+/// # Safety
+///
+/// As for `stats_mean`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_l2(nums: *const f64, len: usize, out: *mut f64) -> c_int {
+    apply(nums, len, out, crate::l2)
+}
+
+/// Median absolute deviation, as for `mad`.
+///
+/// # Safety
+///
+/// As for `stats_mean`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_mad(nums: *const f64, len: usize, out: *mut f64) -> c_int {
+    apply(nums, len, out, crate::mad)
+}
+
+/// Quantile `q`, as for `quantile`.
+///
+/// # Safety
+///
+/// As for `stats_mean`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::ffi::*;
+/// let nums = [4.0, 1.0, 3.0, 2.0];
+/// let mut out = 0.0;
+/// assert_eq!(STATS_OK, unsafe { stats_quantile(nums.as_ptr(), 4, 0.25, &mut out) });
+/// assert_eq!(1.75, out);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn stats_quantile(
+    nums: *const f64,
+    len: usize,
+    q: f64,
+    out: *mut f64,
+) -> c_int {
+    apply(nums, len, out, |nums| crate::quantile(nums, q))
+}
+
+/// New running summary of no values, to be released with
+/// `stats_summary_free`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::ffi::*;
+/// unsafe {
+///     let s = stats_summary_new();
+///     stats_summary_push_all(s, [1.0, 2.0].as_ptr(), 2);
+///     stats_summary_push(s, 6.0);
+///     let (mut count, mut mean) = (0, 0.0);
+///     assert_eq!(STATS_OK, stats_summary_count(s, &mut count));
+///     assert_eq!(STATS_OK, stats_summary_mean(s, &mut mean));
+///     assert_eq!((3, 3.0), (count, mean));
+///     stats_summary_free(s);
+/// }
+/// ```
+#[no_mangle]
+pub extern "C" fn stats_summary_new() -> *mut Summary {
+    Box::into_raw(Box::new(Summary::new()))
+}
+
+/// Release a summary. Null is ignored.
+///
+/// # Safety
+///
+/// `summary` must be null or come from
+/// `stats_summary_new`, and not be used afterward.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_free(summary: *mut Summary) {
+    if !summary.is_null() {
+        drop(Box::from_raw(summary));
+    }
+}
+
+/// Add a value to a summary.
+///
+/// # Safety
+///
+/// `summary` must be null or a live summary.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_push(summary: *mut Summary, x: f64) -> c_int {
+    match summary.as_mut() {
+        Some(summary) => {
+            summary.push(x);
+            STATS_OK
+        }
+        None => STATS_NULL_POINTER,
+    }
+}
+
+/// Add the `len` values at `nums` to a summary.
+///
+/// # Safety
+///
+/// `summary` must be null or a live summary, and `nums`
+/// as for `stats_mean`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_push_all(
+    summary: *mut Summary,
+    nums: *const f64,
+    len: usize,
+) -> c_int {
+    match (summary.as_mut(), values(nums, len)) {
+        (Some(summary), Some(nums)) => {
+            nums.iter().for_each(|&x| summary.push(x));
+            STATS_OK
+        }
+        _ => STATS_NULL_POINTER,
+    }
+}
+
+/// Fold the summary `other` into `summary`. They may be the
+/// same summary, which then counts its values twice.
+///
+/// # Safety
+///
+/// Both must be null or live summaries.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::ffi::*;
+/// unsafe {
+///     let s = stats_summary_new();
+///     stats_summary_push(s, 2.0);
+///     assert_eq!(STATS_OK, stats_summary_merge(s, s));
+///     let mut count = 0;
+///     stats_summary_count(s, &mut count);
+///     assert_eq!(2, count);
+///     stats_summary_free(s);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_merge(
+    summary: *mut Summary,
+    other: *const Summary,
+) -> c_int {
+    // Copy `other` first, so that no shared reference to it
+    // is live while `summary`, which may alias it, is
+    // borrowed mutably.
+    let other = match other.as_ref() {
+        Some(other) => *other,
+        None => return STATS_NULL_POINTER,
+    };
+    match summary.as_mut() {
+        Some(summary) => {
+            summary.merge(&other);
+            STATS_OK
+        }
+        None => STATS_NULL_POINTER,
+    }
+}
+
+/// Number of values in a summary.
+///
+/// # Safety
+///
+/// `summary` must be null or a live summary, and `out`
+/// null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_count(summary: *const Summary, out: *mut u64) -> c_int {
+    match (summary.as_ref(), out.as_mut()) {
+        (Some(summary), Some(out)) => {
+            *out = summary.count();
+            STATS_OK
+        }
+        _ => STATS_NULL_POINTER,
+    }
+}
+
+/// Apply an accessor to a summary.
+unsafe fn query(
+    summary: *const Summary,
+    out: *mut f64,
+    accessor: fn(&Summary) -> Option<f64>,
+) -> c_int {
+    match summary.as_ref() {
+        Some(summary) => write(out, accessor(summary)),
+        None => STATS_NULL_POINTER,
+    }
+}
+
+/// Mean of a summary; undefined for no values.
+///
+/// # Safety
+///
+/// As for `stats_summary_count`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_mean(summary: *const Summary, out: *mut f64) -> c_int {
+    query(summary, out, Summary::mean)
+}
+
+/// Population variance of a summary; undefined for no
+/// values.
+///
+/// # Safety
+///
+/// As for `stats_summary_count`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_variance(summary: *const Summary, out: *mut f64) -> c_int {
+    query(summary, out, Summary::variance)
+}
+
+/// Population standard deviation of a summary; undefined
+/// for no values.
+///
+/// # Safety
+///
+/// As for `stats_summary_count`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_stddev(summary: *const Summary, out: *mut f64) -> c_int {
+    query(summary, out, Summary::stddev)
+}
+
+/// Smallest value in a summary; undefined for no values.
+///
+/// # Safety
+///
+/// As for `stats_summary_count`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_min(summary: *const Summary, out: *mut f64) -> c_int {
+    query(summary, out, Summary::min)
+}
+
+/// Largest value in a summary; undefined for no values.
+///
+/// # Safety
+///
+/// As for `stats_summary_count`.
+#[no_mangle]
+pub unsafe extern "C" fn stats_summary_max(summary: *const Summary, out: *mut f64) -> c_int {
+    query(summary, out, Summary::max)
+}
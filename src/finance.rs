@@ -0,0 +1,126 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of financial return series. Returns are
+//! simple per-period fractions (0.01 for 1%), and
+//! annualization assumes independent periods, scaling
+//! volatility by the square root of `periods_per_year`.
+
+use crate::{mean, sample_variance};
+
+/// Annualized volatility of per-period returns: their
+/// sample standard deviation times `√periods_per_year`. The
+/// volatility is undefined for fewer than two returns or
+/// for a non-positive number of periods per year.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::finance::*;
+/// assert_eq!(None, annualized_volatility(&[0.01], 12.0));
+/// ```
+/// ```
+/// # use stats::finance::*;
+/// let monthly = [0.01, 0.02, -0.01, 0.03];
+/// let v = annualized_volatility(&monthly, 12.0).unwrap();
+/// assert!((v - 0.059160798).abs() < 1e-9);
+/// ```
+pub fn annualized_volatility(returns: &[f64], periods_per_year: f64) -> Option<f64> {
+    if periods_per_year.is_nan() || periods_per_year <= 0.0 {
+        return None;
+    }
+    Some((sample_variance(returns)? * periods_per_year).sqrt())
+}
+
+/// Annualized Sharpe ratio of per-period returns against an
+/// annual risk-free rate: the mean excess return per
+/// period, after subtracting `risk_free / periods_per_year`,
+/// divided by its sample standard deviation and scaled by
+/// `√periods_per_year`. The ratio is undefined for fewer
+/// than two returns, for a non-positive number of periods
+/// per year, or for constant returns.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::finance::*;
+/// assert_eq!(None, sharpe_ratio(&[0.01, 0.01], 0.0, 12.0));
+/// ```
+/// ```
+/// # use stats::finance::*;
+/// let monthly = [0.01, 0.02, -0.01, 0.03];
+/// let s = sharpe_ratio(&monthly, 0.0, 12.0).unwrap();
+/// assert!((s - 2.535462764).abs() < 1e-9);
+/// let s = sharpe_ratio(&monthly, 0.024, 12.0).unwrap();
+/// assert!((s - 2.129788722).abs() < 1e-9);
+/// ```
+pub fn sharpe_ratio(returns: &[f64], risk_free: f64, periods_per_year: f64) -> Option<f64> {
+    let volatility = annualized_volatility(returns, periods_per_year)?;
+    if volatility == 0.0 {
+        return None;
+    }
+    let excess = mean(returns)? - risk_free / periods_per_year;
+    Some(excess * periods_per_year / volatility)
+}
+
+/// Simple returns `nums[i] / nums[i - 1] - 1` of a price or
+/// level series, one shorter than the series. A return is
+/// undefined after a zero level.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::finance::*;
+/// assert!(pct_change(&[100.0]).is_empty());
+/// ```
+/// ```
+/// # use stats::finance::*;
+/// let r = pct_change(&[100.0, 110.0, 99.0, 0.0, 5.0]);
+/// assert_eq!(4, r.len());
+/// assert!((r[0].unwrap() - 0.1).abs() < 1e-15);
+/// assert!((r[1].unwrap() - -0.1).abs() < 1e-15);
+/// assert_eq!((Some(-1.0), None), (r[2], r[3]));
+/// ```
+pub fn pct_change(nums: &[f64]) -> Vec<Option<f64>> {
+    nums.windows(2)
+        .map(|w| {
+            if w[0] == 0.0 {
+                None
+            } else {
+                Some(w[1] / w[0] - 1.0)
+            }
+        })
+        .collect()
+}
+
+/// Log returns `ln(nums[i] / nums[i - 1])` of a price or
+/// level series, one shorter than the series. Log returns
+/// add up over time. A log return is undefined unless both
+/// levels are positive.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::finance::*;
+/// assert!(log_returns(&[]).is_empty());
+/// ```
+/// ```
+/// # use stats::finance::*;
+/// let r = log_returns(&[100.0, 200.0, 100.0, 0.0]);
+/// assert!((r[0].unwrap() - 2f64.ln()).abs() < 1e-15);
+/// assert!((r[0].unwrap() + r[1].unwrap()).abs() < 1e-15);
+/// assert_eq!(None, r[2]);
+/// ```
+pub fn log_returns(nums: &[f64]) -> Vec<Option<f64>> {
+    nums.windows(2)
+        .map(|w| {
+            if w[0] > 0.0 && w[1] > 0.0 {
+                Some((w[1] / w[0]).ln())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
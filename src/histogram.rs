@@ -0,0 +1,411 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Histograms of floating-point values.
+
+use crate::json_number;
+
+/// Counts of values falling in consecutive bins. Bin `i`
+/// covers `[edges[i], edges[i + 1])`, except that the last
+/// bin also includes its upper edge. A histogram built with
+/// a weight per value also totals the weights in each bin.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let h = Histogram::new_weighted(&[0.0, 1.0], &[0.5, 2.0], 2).unwrap();
+/// let json = serde_json::to_string(&h).unwrap();
+/// assert_eq!(h, serde_json::from_str::<Histogram>(&json).unwrap());
+/// // Deserializing checks the edges and the bin lengths.
+/// let empty = r#"{"edges":[],"counts":[],"weights":null}"#;
+/// assert!(serde_json::from_str::<Histogram>(empty).is_err());
+/// let short = r#"{"edges":[0.0,1.0],"counts":[],"weights":null}"#;
+/// assert!(serde_json::from_str::<Histogram>(short).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "HistogramParts"))]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    weights: Option<Vec<f64>>,
+}
+
+/// Fields of a deserialized histogram, before they are
+/// checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct HistogramParts {
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    weights: Option<Vec<f64>>,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<HistogramParts> for Histogram {
+    type Error = &'static str;
+
+    fn try_from(parts: HistogramParts) -> Result<Histogram, Self::Error> {
+        if !valid_edges(&parts.edges) {
+            return Err("histogram edges must be at least two, strictly increasing");
+        }
+        let bins = parts.edges.len() - 1;
+        let weights_fit = parts.weights.iter().all(|w| w.len() == bins);
+        if parts.counts.len() != bins || !weights_fit {
+            return Err("histogram must have one count and weight per bin");
+        }
+        Ok(Histogram {
+            edges: parts.edges,
+            counts: parts.counts,
+            weights: parts.weights,
+        })
+    }
+}
+
+/// Whether `edges` are at least two, strictly increasing.
+fn valid_edges(edges: &[f64]) -> bool {
+    edges.len() >= 2 && edges.windows(2).all(|w| w[0] < w[1])
+}
+
+/// Edges of `bins` equal-width bins spanning the range of
+/// non-empty input.
+fn equal_edges(nums: &[f64], bins: usize) -> Vec<f64> {
+    let mut lo = nums.iter().cloned().fold(f64::INFINITY, f64::min);
+    let mut hi = nums.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if lo == hi {
+        lo -= 0.5;
+        hi += 0.5;
+    }
+    let width = (hi - lo) / bins as f64;
+    let mut edges: Vec<f64> = (0..bins).map(|i| lo + i as f64 * width).collect();
+    edges.push(hi);
+    edges
+}
+
+impl Histogram {
+    /// Histogram of input values with `bins` equal-width
+    /// bins spanning the range of the data. Constant input
+    /// gets a range of width 1 centered on its value. The
+    /// histogram of an empty list, or with no bins, is
+    /// undefined.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Histogram::new(&[], 3));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::new(&[0.0, 1.0, 1.5, 3.0], 3).unwrap();
+    /// assert_eq!([0.0, 1.0, 2.0, 3.0], h.edges());
+    /// assert_eq!([1, 2, 1], h.counts());
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::new(&[2.0, 2.0], 2).unwrap();
+    /// assert_eq!([1.5, 2.0, 2.5], h.edges());
+    /// assert_eq!([0, 2], h.counts());
+    /// ```
+    pub fn new(nums: &[f64], bins: usize) -> Option<Histogram> {
+        if nums.is_empty() || bins == 0 {
+            return None;
+        }
+        Histogram::with_edges(nums, &equal_edges(nums, bins))
+    }
+
+    /// Histogram of input values with `bins` equal-width
+    /// bins as for `new`, where each value counts with the
+    /// corresponding weight. The histogram is undefined as
+    /// for `new`, if the lengths differ, or for a negative
+    /// or non-finite weight.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Histogram::new_weighted(&[1.0], &[], 2));
+    /// assert_eq!(None, Histogram::new_weighted(&[1.0], &[-1.0], 2));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::new_weighted(&[0.0, 1.0, 1.5, 3.0], &[2.0, 1.0, 0.5, 0.5], 3).unwrap();
+    /// assert_eq!([1, 2, 1], h.counts());
+    /// assert_eq!(Some(&[2.0, 1.5, 0.5][..]), h.weights());
+    /// assert_eq!(4.0, h.total_weight());
+    /// ```
+    pub fn new_weighted(nums: &[f64], weights: &[f64], bins: usize) -> Option<Histogram> {
+        if nums.is_empty() || bins == 0 {
+            return None;
+        }
+        Histogram::with_edges_weighted(nums, weights, &equal_edges(nums, bins))
+    }
+
+    /// Histogram of input values over the given bin edges.
+    /// Values outside the edges are not counted. The
+    /// histogram is undefined unless there are at least
+    /// two edges, strictly increasing.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Histogram::with_edges(&[1.0], &[1.0, 1.0]));
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::with_edges(&[-1.0, 0.0, 0.5, 2.0, 9.0], &[0.0, 1.0, 2.0]).unwrap();
+    /// assert_eq!([2, 1], h.counts());
+    /// ```
+    pub fn with_edges(nums: &[f64], edges: &[f64]) -> Option<Histogram> {
+        if !valid_edges(edges) {
+            return None;
+        }
+        let mut hist = Histogram {
+            edges: edges.to_owned(),
+            counts: vec![0; edges.len() - 1],
+            weights: None,
+        };
+        for &v in nums {
+            if let Some(i) = hist.bin_of(v) {
+                hist.counts[i] += 1;
+            }
+        }
+        Some(hist)
+    }
+
+    /// Histogram of input values over the given bin edges as
+    /// for `with_edges`, where each value counts with the
+    /// corresponding weight. The histogram is undefined as
+    /// for `with_edges`, if the lengths differ, or for a
+    /// negative or non-finite weight.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::with_edges_weighted(&[0.5, 1.5, 9.0], &[3.0, 1.0, 5.0], &[0.0, 1.0, 2.0]);
+    /// assert_eq!(Some(&[3.0, 1.0][..]), h.unwrap().weights());
+    /// ```
+    pub fn with_edges_weighted(nums: &[f64], weights: &[f64], edges: &[f64]) -> Option<Histogram> {
+        if nums.len() != weights.len() || !weights.iter().all(|w| w.is_finite() && *w >= 0.0) {
+            return None;
+        }
+        let mut hist = Histogram::with_edges(&[], edges)?;
+        let mut totals = vec![0.0; hist.counts.len()];
+        for (&v, &w) in nums.iter().zip(weights) {
+            if let Some(i) = hist.bin_of(v) {
+                hist.counts[i] += 1;
+                totals[i] += w;
+            }
+        }
+        hist.weights = Some(totals);
+        Some(hist)
+    }
+
+    /// Index of the bin containing `x`, if any.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::with_edges(&[], &[0.0, 1.0, 2.0]).unwrap();
+    /// assert_eq!(Some(0), h.bin_of(0.0));
+    /// assert_eq!(Some(1), h.bin_of(2.0));
+    /// assert_eq!(None, h.bin_of(2.5));
+    /// ```
+    pub fn bin_of(&self, x: f64) -> Option<usize> {
+        if self.edges.len() < 2 {
+            return None;
+        }
+        let last = self.edges.len() - 1;
+        if !(self.edges[0] <= x && x <= self.edges[last]) {
+            return None;
+        }
+        // Index of the first edge greater than x.
+        let upper = self.edges.partition_point(|&e| e <= x);
+        Some((upper - 1).min(last - 1))
+    }
+
+    /// Bin edges, strictly increasing. There is one more
+    /// edge than there are bins.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// Number of values in each bin.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Total weight of the values in each bin, for a
+    /// weighted histogram.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Histogram::new(&[1.0], 2).unwrap().weights());
+    /// ```
+    pub fn weights(&self) -> Option<&[f64]> {
+        self.weights.as_deref()
+    }
+
+    /// Total number of values counted.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Total weight of the values counted: the number of
+    /// values for an unweighted histogram.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(2.0, Histogram::new(&[1.0, 2.0], 2).unwrap().total_weight());
+    /// ```
+    pub fn total_weight(&self) -> f64 {
+        self.masses().iter().sum()
+    }
+
+    /// Weight in each bin, which is its count for an
+    /// unweighted histogram.
+    pub(crate) fn masses(&self) -> Vec<f64> {
+        match &self.weights {
+            Some(weights) => weights.clone(),
+            None => self.counts.iter().map(|&c| c as f64).collect(),
+        }
+    }
+
+    /// The histogram as a JSON object with the fields
+    /// `edges`, an array of numbers, and `counts`, an array
+    /// of integers, and for a weighted histogram `weights`,
+    /// an array of numbers.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::new(&[0.0, 1.0, 1.5, 3.0], 3).unwrap();
+    /// assert_eq!(r#"{"edges":[0,1,2,3],"counts":[1,2,1]}"#, h.to_json());
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::new_weighted(&[0.0, 1.0], &[0.5, 2.0], 1).unwrap();
+    /// assert_eq!(r#"{"edges":[0,1],"counts":[2],"weights":[2.5]}"#, h.to_json());
+    /// ```
+    pub fn to_json(&self) -> String {
+        let numbers = |v: &[f64]| -> String {
+            let v: Vec<String> = v.iter().map(|&x| json_number(Some(x))).collect();
+            v.join(",")
+        };
+        let counts: Vec<String> = self.counts.iter().map(|c| c.to_string()).collect();
+        let weights = match &self.weights {
+            Some(weights) => format!(r#","weights":[{}]"#, numbers(weights)),
+            None => String::new(),
+        };
+        format!(
+            r#"{{"edges":[{}],"counts":[{}]{}}}"#,
+            numbers(&self.edges),
+            counts.join(","),
+            weights
+        )
+    }
+
+    /// Probability density in each bin: the bin's share of
+    /// the total (of weight, for a weighted histogram)
+    /// divided by its width. An empty histogram has zero
+    /// density everywhere.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let h = Histogram::with_edges(&[0.5, 1.5, 2.5, 3.0], &[0.0, 1.0, 3.0]).unwrap();
+    /// assert_eq!(vec![0.25, 0.375], h.density());
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let edges = [0.0, 1.0, 3.0];
+    /// let h = Histogram::with_edges_weighted(&[0.5, 1.5], &[3.0, 1.0], &edges).unwrap();
+    /// assert_eq!(vec![0.75, 0.125], h.density());
+    /// let h = Histogram::with_edges_weighted(&[0.5], &[0.0], &edges).unwrap();
+    /// assert_eq!(vec![0.0, 0.0], h.density());
+    /// ```
+    pub fn density(&self) -> Vec<f64> {
+        let masses = self.masses();
+        let total = masses.iter().sum::<f64>();
+        let total = if total > 0.0 { total } else { 1.0 };
+        masses
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(&m, w)| m / total / (w[1] - w[0]))
+            .collect()
+    }
+}
+
+/// Value of `statistic` on the `values` in each bin, where
+/// `values[i]` falls in the bin of `bin_edges` containing
+/// `x[i]`. Bins are as for `Histogram::with_edges`, and
+/// values whose `x` is outside the edges are left out. A
+/// bin's result is `None` if the bin is empty, whatever the
+/// statistic, or where the statistic is undefined for its
+/// values. The result is undefined if `x` and `values`
+/// differ in length, or for invalid edges.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, binned_statistic(&[1.0], &[], &[0.0, 2.0], median));
+/// assert_eq!(None, binned_statistic(&[1.0], &[1.0], &[2.0, 0.0], median));
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [0.5, 1.5, 1.2, 0.1, 9.0];
+/// let y = [1.0, 10.0, 20.0, 3.0, 100.0];
+/// let profile = binned_statistic(&x, &y, &[0.0, 1.0, 2.0, 3.0], median).unwrap();
+/// assert_eq!(vec![Some(1.0), Some(10.0), None], profile);
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [0.5, 1.5, 1.2];
+/// let y = [1.0, 10.0, 20.0];
+/// let r = binned_statistic(&x, &y, &[0.0, 1.0, 2.0], mean).unwrap();
+/// assert_eq!(vec![Some(1.0), Some(15.0)], r);
+/// // An empty bin has no mean, though `mean(&[])` is 0.
+/// let r = binned_statistic(&[0.5], &[7.0], &[0.0, 1.0, 2.0], mean).unwrap();
+/// assert_eq!(vec![Some(7.0), None], r);
+/// ```
+pub fn binned_statistic<F>(
+    x: &[f64],
+    values: &[f64],
+    bin_edges: &[f64],
+    statistic: F,
+) -> Option<Vec<Option<f64>>>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    if x.len() != values.len() {
+        return None;
+    }
+    let hist = Histogram::with_edges(&[], bin_edges)?;
+    let mut bins = vec![Vec::new(); hist.counts.len()];
+    for (&xi, &v) in x.iter().zip(values) {
+        if let Some(i) = hist.bin_of(xi) {
+            bins[i].push(v);
+        }
+    }
+    Some(
+        bins.iter()
+            .map(|bin| if bin.is_empty() { None } else { statistic(bin) })
+            .collect(),
+    )
+}
@@ -0,0 +1,546 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Hypothesis tests. All p-values are two-sided unless
+//! otherwise noted.
+
+use crate::special::{beta_inc, chi_square_sf, normal_cdf, student_t_cdf};
+use crate::{mean, quantile, sample_variance, ContingencyTable};
+
+/// Outcome of a hypothesis test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    /// Test statistic.
+    pub statistic: f64,
+    /// Degrees of freedom of the statistic's reference
+    /// distribution, for tests that have one.
+    pub df: Option<f64>,
+    /// Probability, under the null hypothesis, of a
+    /// statistic at least as extreme as the one observed.
+    pub p_value: f64,
+}
+
+/// Smallest expected count per category the chi-square
+/// tests accept: below this the chi-square approximation to
+/// the statistic's distribution is unreliable.
+pub const CHI_SQUARE_MIN_EXPECTED: f64 = 5.0;
+
+/// Two-sided p-value of a t statistic.
+fn t_p_value(t: f64, df: f64) -> f64 {
+    (2.0 * student_t_cdf(-t.abs(), df)).min(1.0)
+}
+
+/// Two-sided p-value of a z statistic.
+fn z_p_value(z: f64) -> f64 {
+    (2.0 * normal_cdf(-z.abs())).min(1.0)
+}
+
+/// One-sample t-test of whether the mean of input values
+/// differs from `mu0`. The test is undefined for fewer than
+/// two values or for constant input.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, t_test_one_sample(&[1.0], 0.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = t_test_one_sample(&[1.0, 2.0, 3.0, 4.0, 5.0], 1.0).unwrap();
+/// assert!((r.statistic - 8.0f64.sqrt()).abs() < 1e-12);
+/// assert_eq!(Some(4.0), r.df);
+/// assert!((r.p_value - 0.0474).abs() < 1e-4);
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = t_test_one_sample(&[1.0, 2.0, 3.0, 4.0, 5.0], 3.0).unwrap();
+/// assert_eq!(1.0, r.p_value);
+/// ```
+pub fn t_test_one_sample(nums: &[f64], mu0: f64) -> Option<TestResult> {
+    let var = sample_variance(nums)?;
+    if var == 0.0 {
+        return None;
+    }
+    let n = nums.len() as f64;
+    let statistic = (mean(nums)? - mu0) / (var / n).sqrt();
+    let df = n - 1.0;
+    Some(TestResult {
+        statistic,
+        df: Some(df),
+        p_value: t_p_value(statistic, df),
+    })
+}
+
+/// Assumption about the population variances in a
+/// two-sample t-test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Variances {
+    /// Variances may differ: Welch's t-test, with
+    /// Welch–Satterthwaite degrees of freedom.
+    Unequal,
+    /// Variances are equal: Student's t-test, with a pooled
+    /// variance estimate.
+    Pooled,
+}
+
+/// Two-sample t-test of whether the means of `a` and `b`
+/// differ. The statistic is positive when `a` has the
+/// larger mean. The test is undefined unless each sample
+/// has at least two values and they are not all the same.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, t_test_two_sample(&[1.0], &[1.0, 2.0], Variances::Pooled));
+/// ```
+/// ```
+/// # use stats::*;
+/// let a = [1.0, 2.0, 3.0];
+/// let b = [1.0, 3.0, 5.0, 7.0, 9.0];
+/// let r = t_test_two_sample(&a, &b, Variances::Pooled).unwrap();
+/// assert!((r.statistic + 3.0 / (7.0f64 * 8.0 / 15.0).sqrt()).abs() < 1e-12);
+/// assert_eq!(Some(6.0), r.df);
+/// ```
+pub fn t_test_two_sample(a: &[f64], b: &[f64], variances: Variances) -> Option<TestResult> {
+    let (va, vb) = (sample_variance(a)?, sample_variance(b)?);
+    let (na, nb) = (a.len() as f64, b.len() as f64);
+    let (se2, df) = match variances {
+        Variances::Unequal => {
+            let (sa, sb) = (va / na, vb / nb);
+            let df = (sa + sb).powi(2) / (sa * sa / (na - 1.0) + sb * sb / (nb - 1.0));
+            (sa + sb, df)
+        }
+        Variances::Pooled => {
+            let df = na + nb - 2.0;
+            let pooled = ((na - 1.0) * va + (nb - 1.0) * vb) / df;
+            (pooled * (1.0 / na + 1.0 / nb), df)
+        }
+    };
+    if se2 == 0.0 {
+        return None;
+    }
+    let statistic = (mean(a)? - mean(b)?) / se2.sqrt();
+    Some(TestResult {
+        statistic,
+        df: Some(df),
+        p_value: t_p_value(statistic, df),
+    })
+}
+
+/// Welch's two-sample t-test of whether the means of `a`
+/// and `b` differ, without assuming equal variances. See
+/// `t_test_two_sample`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let r = t_test_welch(&[1.0, 2.0, 3.0, 4.0, 5.0], &[3.0, 4.0, 5.0, 6.0, 7.0]).unwrap();
+/// assert_eq!(-2.0, r.statistic);
+/// assert_eq!(Some(8.0), r.df);
+/// assert!((r.p_value - 0.0805).abs() < 1e-4);
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = t_test_welch(&[1.0, 2.0, 3.0], &[1.0, 3.0, 5.0, 7.0, 9.0]).unwrap();
+/// assert!((r.df.unwrap() - 5.1579).abs() < 1e-4);
+/// ```
+pub fn t_test_welch(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    t_test_two_sample(a, b, Variances::Unequal)
+}
+
+/// Paired t-test of whether the mean of the differences
+/// `after[i] - before[i]` is zero. The statistic is
+/// positive when values tend to increase. The test is
+/// undefined for unequal-length inputs, for fewer than two
+/// pairs, or for constant differences.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, t_test_paired(&[1.0, 2.0], &[1.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let before = [10.0, 12.0, 9.0, 11.0, 13.0];
+/// let after = [11.0, 14.0, 10.0, 13.0, 14.0];
+/// let r = t_test_paired(&before, &after).unwrap();
+/// let d = t_test_one_sample(&[1.0, 2.0, 1.0, 2.0, 1.0], 0.0).unwrap();
+/// assert_eq!(d, r);
+/// assert!(r.statistic > 0.0 && r.p_value < 0.01);
+/// ```
+pub fn t_test_paired(before: &[f64], after: &[f64]) -> Option<TestResult> {
+    if before.len() != after.len() {
+        return None;
+    }
+    let differences: Vec<f64> = before.iter().zip(after).map(|(b, a)| a - b).collect();
+    t_test_one_sample(&differences, 0.0)
+}
+
+/// One-sample z-test of whether the mean of input values
+/// differs from `mu0`, given the known population standard
+/// deviation `sigma`. The test is undefined for an empty
+/// list or non-positive `sigma`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, z_test(&[1.0], 0.0, 0.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = z_test(&[1.0, 2.0, 3.0, 4.0, 5.0], 2.0, 1.0).unwrap();
+/// assert!((r.statistic - 5.0f64.sqrt()).abs() < 1e-12);
+/// assert_eq!(None, r.df);
+/// assert!((r.p_value - 0.025347).abs() < 1e-6);
+/// ```
+pub fn z_test(nums: &[f64], mu0: f64, sigma: f64) -> Option<TestResult> {
+    if nums.is_empty() || sigma.is_nan() || sigma <= 0.0 {
+        return None;
+    }
+    let statistic = (mean(nums)? - mu0) / (sigma / (nums.len() as f64).sqrt());
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value: z_p_value(statistic),
+    })
+}
+
+/// Two-sample z-test of whether the means of `a` and `b`
+/// differ, given their known population standard deviations.
+/// The test is undefined for an empty sample or
+/// non-positive standard deviations.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let r = z_test_two_sample(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5.0; 5], 1.0, 2.0).unwrap();
+/// assert_eq!(-2.0, r.statistic);
+/// assert!((r.p_value - 0.0455).abs() < 1e-4);
+/// ```
+pub fn z_test_two_sample(a: &[f64], b: &[f64], sigma_a: f64, sigma_b: f64) -> Option<TestResult> {
+    if [sigma_a, sigma_b].iter().any(|s| s.is_nan() || *s <= 0.0) || a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let se = (sigma_a * sigma_a / a.len() as f64 + sigma_b * sigma_b / b.len() as f64).sqrt();
+    let statistic = (mean(a)? - mean(b)?) / se;
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value: z_p_value(statistic),
+    })
+}
+
+/// Two-proportion z-test of whether the success rates
+/// `successes_a / trials_a` and `successes_b / trials_b`
+/// differ, using the pooled proportion for the standard
+/// error. The test is undefined for zero trials, for more
+/// successes than trials, or when every trial (or none)
+/// succeeded.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, z_test_proportions(5, 5, 10, 10));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = z_test_proportions(45, 100, 30, 100).unwrap();
+/// assert!((r.statistic - 2.19089).abs() < 1e-5);
+/// assert!((r.p_value - 0.02846).abs() < 1e-5);
+/// ```
+pub fn z_test_proportions(
+    successes_a: u64,
+    trials_a: u64,
+    successes_b: u64,
+    trials_b: u64,
+) -> Option<TestResult> {
+    if trials_a == 0 || trials_b == 0 || successes_a > trials_a || successes_b > trials_b {
+        return None;
+    }
+    let (na, nb) = (trials_a as f64, trials_b as f64);
+    let pooled = (successes_a + successes_b) as f64 / (na + nb);
+    let se2 = pooled * (1.0 - pooled) * (1.0 / na + 1.0 / nb);
+    if se2 == 0.0 {
+        return None;
+    }
+    let statistic = (successes_a as f64 / na - successes_b as f64 / nb) / se2.sqrt();
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value: z_p_value(statistic),
+    })
+}
+
+/// Chi-square goodness-of-fit test of whether `observed`
+/// category counts match the `expected` counts, with one
+/// fewer degrees of freedom than categories. The test is
+/// undefined unless there are at least two categories, the
+/// lengths and totals of the two lists agree, observed
+/// counts are non-negative, and every expected count is at
+/// least `CHI_SQUARE_MIN_EXPECTED`.
+/// The p-value is upper-tail.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, chi_square_gof(&[5.0, 5.0], &[2.0, 8.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let observed = [5.0, 8.0, 9.0, 8.0, 10.0, 20.0];
+/// let r = chi_square_gof(&observed, &[10.0; 6]).unwrap();
+/// assert!((r.statistic - 13.4).abs() < 1e-12);
+/// assert_eq!(Some(5.0), r.df);
+/// assert!((r.p_value - 0.0199).abs() < 1e-4);
+/// ```
+pub fn chi_square_gof(observed: &[f64], expected: &[f64]) -> Option<TestResult> {
+    if observed.len() < 2 || observed.len() != expected.len() {
+        return None;
+    }
+    if !expected.iter().all(|&e| e >= CHI_SQUARE_MIN_EXPECTED)
+        || !observed.iter().all(|&o| o >= 0.0)
+    {
+        return None;
+    }
+    let (total_o, total_e) = (observed.iter().sum::<f64>(), expected.iter().sum::<f64>());
+    if (total_o - total_e).abs() > 1e-8 * total_e {
+        return None;
+    }
+    let statistic = observed
+        .iter()
+        .zip(expected)
+        .map(|(o, e)| (o - e).powi(2) / e)
+        .sum::<f64>();
+    let df = (observed.len() - 1) as f64;
+    Some(TestResult {
+        statistic,
+        df: Some(df),
+        p_value: chi_square_sf(statistic, df),
+    })
+}
+
+/// Outcome of a chi-square test of independence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IndependenceResult {
+    /// The chi-square test itself.
+    pub test: TestResult,
+    /// Cramér's V effect size, from 0 (no association) to 1
+    /// (complete association).
+    pub cramers_v: f64,
+}
+
+/// Chi-square test of independence of the rows and columns
+/// of a contingency table, with `(rows - 1)(cols - 1)`
+/// degrees of freedom. The test is undefined unless the
+/// table is at least 2×2 and every expected count is at
+/// least `CHI_SQUARE_MIN_EXPECTED`. The p-value is
+/// upper-tail.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let t = ContingencyTable::new(&["a", "b"], &[1, 2]).unwrap();
+/// assert_eq!(None, chi_square_independence(&t));
+/// ```
+/// ```
+/// # use stats::*;
+/// let t = ContingencyTable::from_counts(vec![vec![10, 20], vec![30, 40]]).unwrap();
+/// let r = chi_square_independence(&t).unwrap();
+/// assert!((r.test.statistic - 0.793651).abs() < 1e-6);
+/// assert_eq!(Some(1.0), r.test.df);
+/// assert!((r.test.p_value - 0.372998).abs() < 1e-6);
+/// assert!((r.cramers_v - 0.089087).abs() < 1e-6);
+/// ```
+pub fn chi_square_independence<R, C>(table: &ContingencyTable<R, C>) -> Option<IndependenceResult> {
+    let (rows, cols) = (table.rows.len(), table.cols.len());
+    if rows < 2 || cols < 2 {
+        return None;
+    }
+    let expected = table.expected();
+    if !expected
+        .iter()
+        .flatten()
+        .all(|&e| e >= CHI_SQUARE_MIN_EXPECTED)
+    {
+        return None;
+    }
+    let statistic = table
+        .observed
+        .iter()
+        .flatten()
+        .zip(expected.iter().flatten())
+        .map(|(&o, e)| (o as f64 - e).powi(2) / e)
+        .sum::<f64>();
+    let df = ((rows - 1) * (cols - 1)) as f64;
+    let k = (rows.min(cols) - 1) as f64;
+    Some(IndependenceResult {
+        test: TestResult {
+            statistic,
+            df: Some(df),
+            p_value: chi_square_sf(statistic, df),
+        },
+        cramers_v: (statistic / (table.total() as f64 * k)).sqrt(),
+    })
+}
+
+/// Upper-tail p-value of an F statistic.
+fn f_p_value(f: f64, df1: f64, df2: f64) -> f64 {
+    beta_inc(0.5 * df2, 0.5 * df1, df2 / (df2 + df1 * f))
+}
+
+/// Outcome of a one-way analysis of variance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnovaResult {
+    /// F statistic: between-group over within-group mean
+    /// square.
+    pub f: f64,
+    /// Between-group degrees of freedom.
+    pub df_between: f64,
+    /// Within-group degrees of freedom.
+    pub df_within: f64,
+    /// Upper-tail p-value of the F statistic.
+    pub p_value: f64,
+    /// Eta-squared effect size: the share of the total sum
+    /// of squares explained by group membership.
+    pub eta_squared: f64,
+}
+
+/// One-way analysis of variance of whether the group means
+/// differ. The analysis is undefined unless there are at
+/// least two non-empty groups, more values than groups, and
+/// some variation within groups.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, anova_one_way(&[&[1.0, 2.0]]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = anova_one_way(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]]).unwrap();
+/// assert!((r.f - 27.0).abs() < 1e-12);
+/// assert_eq!((2.0, 6.0), (r.df_between, r.df_within));
+/// assert!((r.p_value - 0.001).abs() < 1e-12);
+/// assert!((r.eta_squared - 0.9).abs() < 1e-12);
+/// ```
+pub fn anova_one_way(groups: &[&[f64]]) -> Option<AnovaResult> {
+    if groups.len() < 2 || groups.iter().any(|g| g.is_empty()) {
+        return None;
+    }
+    let count = groups.iter().map(|g| g.len()).sum::<usize>();
+    if count <= groups.len() {
+        return None;
+    }
+    let grand = groups.iter().flat_map(|g| g.iter()).sum::<f64>() / count as f64;
+    let (mut between, mut within) = (0.0, 0.0);
+    for g in groups {
+        let m = mean(g)?;
+        between += g.len() as f64 * (m - grand).powi(2);
+        within += g.iter().map(|v| (v - m).powi(2)).sum::<f64>();
+    }
+    if within == 0.0 {
+        return None;
+    }
+    let df_between = (groups.len() - 1) as f64;
+    let df_within = (count - groups.len()) as f64;
+    let f = (between / df_between) / (within / df_within);
+    Some(AnovaResult {
+        f,
+        df_between,
+        df_within,
+        p_value: f_p_value(f, df_between, df_within),
+        eta_squared: between / (between + within),
+    })
+}
+
+/// Brown–Forsythe variant of Levene's test of whether the
+/// groups have equal variances: a one-way analysis of
+/// variance of each value's absolute deviation from its
+/// group's (interpolated) median. Its `eta_squared` is that
+/// of the deviations. The test is undefined when
+/// `anova_one_way` of the deviations is.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let r = levene(&[&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 6.0, 8.0]]).unwrap();
+/// assert!((r.f - 2.4).abs() < 1e-12);
+/// assert_eq!((1.0, 6.0), (r.df_between, r.df_within));
+/// let deviations = [[1.5, 0.5, 0.5, 1.5], [3.0, 1.0, 1.0, 3.0]];
+/// let t = t_test_two_sample(&deviations[0], &deviations[1], Variances::Pooled).unwrap();
+/// assert!((r.p_value - t.p_value).abs() < 1e-12);
+/// ```
+pub fn levene(groups: &[&[f64]]) -> Option<AnovaResult> {
+    let deviations: Vec<Vec<f64>> = groups
+        .iter()
+        .map(|g| {
+            let m = quantile(g, 0.5)?;
+            Some(g.iter().map(|v| (v - m).abs()).collect())
+        })
+        .collect::<Option<_>>()?;
+    let deviations: Vec<&[f64]> = deviations.iter().map(|d| d.as_slice()).collect();
+    anova_one_way(&deviations)
+}
+
+/// Bartlett's test of whether the groups have equal
+/// variances, assuming each is normal. The statistic is
+/// compared against the chi-square distribution with one
+/// fewer degrees of freedom than groups; the p-value is
+/// upper-tail. The test is undefined for fewer than two
+/// groups, or unless each group has at least two values and
+/// non-zero variance.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, bartlett(&[&[1.0, 2.0], &[3.0, 3.0]]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = bartlett(&[&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 6.0, 8.0]]).unwrap();
+/// assert!((r.statistic - 1.147595).abs() < 1e-6);
+/// assert_eq!(Some(1.0), r.df);
+/// assert!((r.p_value - 0.284053).abs() < 1e-6);
+/// ```
+pub fn bartlett(groups: &[&[f64]]) -> Option<TestResult> {
+    if groups.len() < 2 {
+        return None;
+    }
+    let variances: Vec<f64> = groups
+        .iter()
+        .map(|g| sample_variance(g).filter(|&v| v > 0.0))
+        .collect::<Option<_>>()?;
+    let k = groups.len() as f64;
+    let dfs: Vec<f64> = groups.iter().map(|g| (g.len() - 1) as f64).collect();
+    let df_total = dfs.iter().sum::<f64>();
+    let pooled = dfs.iter().zip(&variances).map(|(d, v)| d * v).sum::<f64>() / df_total;
+    let spread = df_total * pooled.ln()
+        - dfs
+            .iter()
+            .zip(&variances)
+            .map(|(d, v)| d * v.ln())
+            .sum::<f64>();
+    let correction =
+        1.0 + (dfs.iter().map(|d| 1.0 / d).sum::<f64>() - 1.0 / df_total) / (3.0 * (k - 1.0));
+    let statistic = spread / correction;
+    let df = k - 1.0;
+    Some(TestResult {
+        statistic,
+        df: Some(df),
+        p_value: chi_square_sf(statistic, df),
+    })
+}
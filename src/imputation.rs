@@ -0,0 +1,129 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Filling in missing values, marked as NaN. Data marked
+//! with `None` instead can be converted with
+//! `none_to_nan`.
+
+use crate::{mean, median};
+
+/// Values with the missing ones filled in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Imputation {
+    /// The input with each NaN replaced.
+    pub values: Vec<f64>,
+    /// Number of values replaced.
+    pub filled: usize,
+}
+
+/// The values, with NaN for each `None`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let v = none_to_nan(&[Some(1.0), None]);
+/// assert_eq!(1.0, v[0]);
+/// assert!(v[1].is_nan());
+/// ```
+pub fn none_to_nan(values: &[Option<f64>]) -> Vec<f64> {
+    values.iter().map(|v| v.unwrap_or(f64::NAN)).collect()
+}
+
+/// Replace each NaN with `fill`.
+fn fill_with(nums: &[f64], fill: f64) -> Imputation {
+    let filled = nums.iter().filter(|x| x.is_nan()).count();
+    let values = nums
+        .iter()
+        .map(|&x| if x.is_nan() { fill } else { x })
+        .collect();
+    Imputation { values, filled }
+}
+
+/// Input values other than NaN.
+fn present(nums: &[f64]) -> Vec<f64> {
+    nums.iter().cloned().filter(|x| !x.is_nan()).collect()
+}
+
+/// Replace each NaN with the mean of the other values. The
+/// imputation is undefined if every value is NaN.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, impute_mean(&[f64::NAN]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = impute_mean(&[1.0, f64::NAN, 5.0, f64::NAN]).unwrap();
+/// assert_eq!(vec![1.0, 3.0, 5.0, 3.0], r.values);
+/// assert_eq!(2, r.filled);
+/// ```
+pub fn impute_mean(nums: &[f64]) -> Option<Imputation> {
+    let present = present(nums);
+    if present.is_empty() {
+        return None;
+    }
+    Some(fill_with(nums, mean(&present)?))
+}
+
+/// Replace each NaN with the median of the other values,
+/// as for `median`. The imputation is undefined if every
+/// value is NaN.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let r = impute_median(&[1.0, f64::NAN, 2.0, 100.0]).unwrap();
+/// assert_eq!(vec![1.0, 2.0, 2.0, 100.0], r.values);
+/// assert_eq!(1, r.filled);
+/// ```
+pub fn impute_median(nums: &[f64]) -> Option<Imputation> {
+    Some(fill_with(nums, median(&present(nums))?))
+}
+
+/// Replace each NaN by linear interpolation, by position,
+/// between the nearest values on either side. NaN before
+/// the first value or after the last takes that nearest
+/// value. The imputation is undefined if every value is
+/// NaN.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, impute_interpolate(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nan = f64::NAN;
+/// let r = impute_interpolate(&[nan, 1.0, nan, nan, 4.0, nan]).unwrap();
+/// assert_eq!(vec![1.0, 1.0, 2.0, 3.0, 4.0, 4.0], r.values);
+/// assert_eq!(4, r.filled);
+/// ```
+pub fn impute_interpolate(nums: &[f64]) -> Option<Imputation> {
+    let known: Vec<usize> = (0..nums.len()).filter(|&i| !nums[i].is_nan()).collect();
+    let (&first, &last) = (known.first()?, known.last()?);
+    let mut values = nums.to_owned();
+    for v in &mut values[..first] {
+        *v = nums[first];
+    }
+    for v in &mut values[last + 1..] {
+        *v = nums[last];
+    }
+    for pair in known.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let step = (nums[b] - nums[a]) / (b - a) as f64;
+        for (offset, v) in values[a + 1..b].iter_mut().enumerate() {
+            *v = nums[a] + step * (offset + 1) as f64;
+        }
+    }
+    Some(Imputation {
+        values,
+        filled: nums.len() - known.len(),
+    })
+}
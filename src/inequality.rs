@@ -0,0 +1,167 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Measures of inequality, concentration and diversity.
+
+use crate::{entropy_from_counts, sorted};
+
+/// Sorted copy of non-negative input values with a positive
+/// total, along with that total.
+fn sorted_shares(nums: &[f64]) -> Option<(Vec<f64>, f64)> {
+    if !nums.iter().all(|&v| v >= 0.0) {
+        return None;
+    }
+    let total = nums.iter().sum::<f64>();
+    if total > 0.0 {
+        Some((sorted(nums), total))
+    } else {
+        None
+    }
+}
+
+/// Gini coefficient of non-negative input values: 0 for
+/// perfect equality, approaching 1 as a single value holds
+/// the entire total. The Gini coefficient is undefined for
+/// an empty list, for negative values, or for a zero total.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, gini(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(0.0), gini(&[2.0, 2.0, 2.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(0.75), gini(&[0.0, 5.0, 0.0, 0.0]));
+/// ```
+pub fn gini(nums: &[f64]) -> Option<f64> {
+    let (nums, total) = sorted_shares(nums)?;
+    let n = nums.len() as f64;
+    let weighted = nums
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (i + 1) as f64 * v)
+        .sum::<f64>();
+    Some(2.0 * weighted / (n * total) - (n + 1.0) / n)
+}
+
+/// Lorenz curve of non-negative input values: the points
+/// `(i / n, share of the total held by the smallest i
+/// values)` for `i` in `0..=n`. The curve is empty when the
+/// Gini coefficient is undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(lorenz_curve(&[-1.0, 2.0]).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let curve = lorenz_curve(&[3.0, 1.0]);
+/// assert_eq!(vec![(0.0, 0.0), (0.5, 0.25), (1.0, 1.0)], curve);
+/// ```
+pub fn lorenz_curve(nums: &[f64]) -> Vec<(f64, f64)> {
+    let (nums, total) = match sorted_shares(nums) {
+        Some(shares) => shares,
+        None => return Vec::new(),
+    };
+    let n = nums.len() as f64;
+    let mut cumulative = 0.0;
+    let mut curve = vec![(0.0, 0.0)];
+    for (i, v) in nums.iter().enumerate() {
+        cumulative += v;
+        curve.push(((i + 1) as f64 / n, cumulative / total));
+    }
+    curve
+}
+
+/// Herfindahl–Hirschman index of non-negative shares, such
+/// as market shares or per-shard traffic: the sum of the
+/// squares of each share's fraction of the total. It ranges
+/// from `1 / n` for equal shares to 1 when one share holds
+/// the entire total. The index is undefined for an empty
+/// list, for negative shares, or for a zero total.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, herfindahl_index(&[0.0, 0.0]));
+/// assert_eq!(Some(1.0), herfindahl_index(&[0.0, 7.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let hhi = herfindahl_index(&[50.0, 30.0, 20.0]).unwrap();
+/// assert!((hhi - 0.38).abs() < 1e-12);
+/// ```
+pub fn herfindahl_index(shares: &[f64]) -> Option<f64> {
+    let (shares, total) = sorted_shares(shares)?;
+    Some(shares.iter().map(|s| (s / total).powi(2)).sum())
+}
+
+/// Gini–Simpson diversity of category counts: the
+/// probability that two items drawn without replacement
+/// fall in different categories. It is 0 when every item is
+/// in one category. The diversity is undefined for fewer
+/// than two items.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, simpson_diversity(&[1, 0]));
+/// assert_eq!(Some(0.0), simpson_diversity(&[4, 0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let d = simpson_diversity(&[2, 1, 1]).unwrap();
+/// assert!((d - 5.0 / 6.0).abs() < 1e-12);
+/// let d = simpson_diversity(&[5_000_000_000, 5_000_000_000]).unwrap();
+/// assert!((d - 0.5).abs() < 1e-9);
+/// ```
+pub fn simpson_diversity(counts: &[usize]) -> Option<f64> {
+    // In floating point, since products of large counts
+    // overflow integers.
+    let total = counts.iter().map(|&c| c as f64).sum::<f64>();
+    if total < 2.0 {
+        return None;
+    }
+    let pairs = counts
+        .iter()
+        .map(|&c| c as f64 * (c as f64 - 1.0).max(0.0))
+        .sum::<f64>();
+    Some(1.0 - pairs / (total * (total - 1.0)))
+}
+
+/// Shannon evenness (Pielou's J) of category counts: the
+/// entropy of the counts divided by its largest possible
+/// value, the logarithm of the number of categories
+/// present. It is 1 when the present categories are equally
+/// common. The evenness is undefined for fewer than two
+/// categories present.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, shannon_evenness(&[3, 0]));
+/// let j = shannon_evenness(&[5, 5, 0]).unwrap();
+/// assert!((j - 1.0).abs() < 1e-12);
+/// ```
+/// ```
+/// # use stats::*;
+/// let j = shannon_evenness(&[2, 1, 1]).unwrap();
+/// assert!((j - 1.5 / 3f64.log2()).abs() < 1e-12);
+/// ```
+pub fn shannon_evenness(counts: &[usize]) -> Option<f64> {
+    let present = counts.iter().filter(|&&c| c > 0).count();
+    // Entropy in base `present` is relative to its maximum.
+    entropy_from_counts(counts, present as f64)
+}
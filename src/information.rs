@@ -0,0 +1,289 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Information-theoretic measures of discrete
+//! distributions. Each takes the base of the logarithm:
+//! 2 for bits, `e` for nats.
+
+use crate::{ContingencyTable, Histogram};
+
+/// How far from 1 a list of probabilities may sum and still
+/// be accepted as a distribution.
+pub const PROBABILITY_TOLERANCE: f64 = 1e-6;
+
+/// Whether `probabilities` is a non-empty list of
+/// non-negative values summing to 1.
+fn is_distribution(probabilities: &[f64]) -> bool {
+    !probabilities.is_empty()
+        && probabilities.iter().all(|&p| p >= 0.0)
+        && (probabilities.iter().sum::<f64>() - 1.0).abs() <= PROBABILITY_TOLERANCE
+}
+
+/// Natural logarithm of a usable logarithm base.
+fn ln_base(base: f64) -> Option<f64> {
+    if base > 0.0 && base != 1.0 && base.is_finite() {
+        Some(base.ln())
+    } else {
+        None
+    }
+}
+
+/// Shannon entropy `-Σ p log p` of a discrete distribution,
+/// with `0 log 0` taken as 0. The entropy is undefined
+/// unless the probabilities are non-negative and sum to 1
+/// within `PROBABILITY_TOLERANCE`, or for a base that is
+/// not positive or is 1.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, entropy(&[0.5, 0.4], 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(1.0), entropy(&[0.5, 0.5], 2.0));
+/// assert_eq!(Some(2.0), entropy(&[0.25; 4], 2.0));
+/// assert_eq!(Some(0.0), entropy(&[1.0, 0.0], 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nats = entropy(&[0.5, 0.5], std::f64::consts::E).unwrap();
+/// assert!((nats - 2f64.ln()).abs() < 1e-15);
+/// ```
+pub fn entropy(probabilities: &[f64], base: f64) -> Option<f64> {
+    let ln_base = ln_base(base)?;
+    if !is_distribution(probabilities) {
+        return None;
+    }
+    let nats = -probabilities
+        .iter()
+        .filter(|&&p| p > 0.0)
+        .map(|p| p * p.ln())
+        .sum::<f64>();
+    Some(nats / ln_base)
+}
+
+/// Shannon entropy of the empirical distribution given by
+/// category counts. The entropy is undefined for a zero
+/// total or an unusable base.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, entropy_from_counts(&[0, 0], 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(1.5), entropy_from_counts(&[2, 1, 1], 2.0));
+/// ```
+pub fn entropy_from_counts(counts: &[usize], base: f64) -> Option<f64> {
+    let total = counts.iter().sum::<usize>();
+    if total == 0 {
+        return None;
+    }
+    let probabilities: Vec<f64> = counts.iter().map(|&c| c as f64 / total as f64).collect();
+    entropy(&probabilities, base)
+}
+
+/// How `kl_divergence` and `cross_entropy` treat a bin where
+/// `q` is zero but `p` is not, which makes them infinite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZeroBins {
+    /// The result is undefined.
+    Undefined,
+    /// Add this positive pseudo-probability to every bin of
+    /// both distributions and renormalize first.
+    Smooth(f64),
+}
+
+/// The two distributions, validated and smoothed as
+/// `zero_bins` directs.
+fn prepare(p: &[f64], q: &[f64], zero_bins: ZeroBins) -> Option<(Vec<f64>, Vec<f64>)> {
+    if p.len() != q.len() || !is_distribution(p) || !is_distribution(q) {
+        return None;
+    }
+    match zero_bins {
+        ZeroBins::Undefined => Some((p.to_vec(), q.to_vec())),
+        ZeroBins::Smooth(epsilon) if epsilon > 0.0 && epsilon.is_finite() => {
+            let total = 1.0 + epsilon * p.len() as f64;
+            let smooth = |d: &[f64]| d.iter().map(|v| (v + epsilon) / total).collect();
+            Some((smooth(p), smooth(q)))
+        }
+        ZeroBins::Smooth(_) => None,
+    }
+}
+
+/// Sum of `p log q` over the bins where `p` is positive, in
+/// nats, or `None` if some such `q` is zero.
+fn expected_log(p: &[f64], q: &[f64]) -> Option<f64> {
+    let mut sum = 0.0;
+    for (&p, &q) in p.iter().zip(q) {
+        if p > 0.0 {
+            if q == 0.0 {
+                return None;
+            }
+            sum += p * q.ln();
+        }
+    }
+    Some(sum)
+}
+
+/// Kullback–Leibler divergence `Σ p log(p / q)` of `q` from
+/// `p`: the information lost when `q` is used to
+/// approximate `p`. It is zero only for identical
+/// distributions, and not symmetric. Like `entropy`, the
+/// divergence is undefined for lists that are not
+/// distributions or for an unusable base; it is also
+/// undefined for distributions of unequal length, or at a
+/// zero bin of `q` unless smoothed.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let p = [0.5, 0.5];
+/// assert_eq!(None, kl_divergence(&p, &[1.0, 0.0], ZeroBins::Undefined, 2.0));
+/// assert_eq!(Some(0.0), kl_divergence(&p, &p, ZeroBins::Undefined, 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let d = kl_divergence(&[0.5, 0.5], &[0.25, 0.75], ZeroBins::Undefined, 2.0).unwrap();
+/// assert!((d - (1.0 - 0.5 * 3f64.log2())).abs() < 1e-15);
+/// ```
+/// ```
+/// # use stats::*;
+/// // Histograms of two runs, one with an empty bin.
+/// let p = [0.2, 0.3, 0.5];
+/// let q = [0.0, 0.5, 0.5];
+/// let d = kl_divergence(&p, &q, ZeroBins::Smooth(1e-3), 2.0).unwrap();
+/// assert!(d.is_finite() && d > 1.0);
+/// ```
+pub fn kl_divergence(p: &[f64], q: &[f64], zero_bins: ZeroBins, base: f64) -> Option<f64> {
+    let ln_base = ln_base(base)?;
+    let (p, q) = prepare(p, q, zero_bins)?;
+    let divergence = expected_log(&p, &p)? - expected_log(&p, &q)?;
+    Some(divergence.max(0.0) / ln_base)
+}
+
+/// Cross-entropy `-Σ p log q` of `q` relative to `p`: the
+/// entropy of `p` plus the KL divergence of `q` from `p`.
+/// The cross-entropy is undefined when that divergence is.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let p = [0.5, 0.5];
+/// assert_eq!(Some(1.0), cross_entropy(&p, &p, ZeroBins::Undefined, 2.0));
+/// let h = cross_entropy(&p, &[0.25, 0.75], ZeroBins::Undefined, 2.0).unwrap();
+/// let kl = kl_divergence(&p, &[0.25, 0.75], ZeroBins::Undefined, 2.0).unwrap();
+/// assert!((h - (1.0 + kl)).abs() < 1e-15);
+/// ```
+pub fn cross_entropy(p: &[f64], q: &[f64], zero_bins: ZeroBins, base: f64) -> Option<f64> {
+    let ln_base = ln_base(base)?;
+    let (p, q) = prepare(p, q, zero_bins)?;
+    Some(-expected_log(&p, &q)? / ln_base)
+}
+
+/// Mutual information of paired samples, estimated from a
+/// two-dimensional histogram with `bins` equal-width bins
+/// along each axis: the KL divergence of the product of the
+/// marginal distributions from the joint distribution. It
+/// is zero for independent samples and detects any kind of
+/// dependence, not just linear or monotone. The estimate is
+/// biased upward for small samples relative to the number
+/// of bins. It is undefined for empty or unequal-length
+/// samples, for no bins, or for an unusable base.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, mutual_information(&[1.0], &[1.0, 2.0], 2, 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [0.0, 0.0, 1.0, 1.0];
+/// let y = [0.0, 1.0, 0.0, 1.0];
+/// assert_eq!(Some(0.0), mutual_information(&x, &y, 2, 2.0));
+/// assert_eq!(Some(1.0), mutual_information(&x, &x, 2, 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// // y depends on x, but is uncorrelated with it.
+/// let x: Vec<f64> = (-50..=50).map(|i| i as f64 / 50.0).collect();
+/// let y: Vec<f64> = x.iter().map(|x| x * x).collect();
+/// let r = correlation_matrix(&[&x, &y], CorrelationMethod::Pearson).unwrap();
+/// assert!(r[0][1].abs() < 1e-12);
+/// assert!(mutual_information(&x, &y, 8, 2.0).unwrap() > 1.0);
+/// ```
+pub fn mutual_information(x: &[f64], y: &[f64], bins: usize, base: f64) -> Option<f64> {
+    let ln_base = ln_base(base)?;
+    if x.len() != y.len() {
+        return None;
+    }
+    let bin_all = |nums: &[f64]| -> Option<Vec<usize>> {
+        let hist = Histogram::new(nums, bins)?;
+        nums.iter().map(|&v| hist.bin_of(v)).collect()
+    };
+    let table = ContingencyTable::new(&bin_all(x)?, &bin_all(y)?)?;
+    let (rows, cols) = (table.row_totals(), table.col_totals());
+    let n = table.total() as f64;
+    let mut nats = 0.0;
+    for (i, row) in table.observed.iter().enumerate() {
+        for (j, &count) in row.iter().enumerate() {
+            if count > 0 {
+                let count = count as f64;
+                nats += count / n * (count * n / (rows[i] as f64 * cols[j] as f64)).ln();
+            }
+        }
+    }
+    Some(nats.max(0.0) / ln_base)
+}
+
+/// Jensen–Shannon divergence of two distributions: the
+/// mean KL divergence of each from their average. Unlike
+/// the KL divergence it is symmetric, always finite, and at
+/// most 1 in base 2. The divergence is undefined for
+/// distributions of unequal length, for lists that are not
+/// distributions, or for an unusable base.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, js_divergence(&[1.0], &[0.5, 0.5], 2.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(1.0), js_divergence(&[1.0, 0.0], &[0.0, 1.0], 2.0));
+/// assert_eq!(Some(0.0), js_divergence(&[0.3, 0.7], &[0.3, 0.7], 2.0));
+/// let pq = js_divergence(&[0.5, 0.5], &[0.9, 0.1], 2.0);
+/// assert_eq!(pq, js_divergence(&[0.9, 0.1], &[0.5, 0.5], 2.0));
+/// ```
+pub fn js_divergence(p: &[f64], q: &[f64], base: f64) -> Option<f64> {
+    let (p, q) = prepare(p, q, ZeroBins::Undefined)?;
+    let m: Vec<f64> = p.iter().zip(&q).map(|(a, b)| 0.5 * (a + b)).collect();
+    let kl = |d: &[f64]| kl_divergence(d, &m, ZeroBins::Undefined, base);
+    Some(0.5 * (kl(&p)? + kl(&q)?))
+}
+
+/// Jensen–Shannon distance: the square root of the
+/// Jensen–Shannon divergence, which is a metric. The
+/// distance is undefined when the divergence is.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let d = js_distance(&[0.5, 0.5], &[1.0, 0.0], 2.0).unwrap();
+/// let js = js_divergence(&[0.5, 0.5], &[1.0, 0.0], 2.0).unwrap();
+/// assert!((d * d - js).abs() < 1e-15);
+/// ```
+pub fn js_distance(p: &[f64], q: &[f64], base: f64) -> Option<f64> {
+    js_divergence(p, q, base).map(f64::sqrt)
+}
@@ -0,0 +1,104 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Reading numeric columns from CSV input.
+
+use crate::StatsError;
+use std::io::Read;
+use std::path::Path;
+
+/// Column of a CSV file to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column<'a> {
+    /// The column with this header.
+    Name(&'a str),
+    /// The column at this position, starting at 0.
+    Index(usize),
+}
+
+/// What `read_column` does with an empty field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Missing {
+    /// Fail with `StatsError::MissingValue`.
+    Error,
+    /// Leave the row out.
+    Skip,
+    /// Use this value instead, such as NaN.
+    Value(f64),
+}
+
+/// Numbers in one column of CSV input with a header row.
+/// Fields are trimmed of surrounding whitespace; a field
+/// that is then empty, or a row too short to have the
+/// column, counts as missing.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let data = "host,latency\na,12.5\nb,\nc, 7.5\n";
+/// let v = read_column(data.as_bytes(), Column::Name("latency"), Missing::Skip).unwrap();
+/// assert_eq!(vec![12.5, 7.5], v);
+/// let v = read_column(data.as_bytes(), Column::Index(1), Missing::Value(0.0)).unwrap();
+/// assert_eq!(vec![12.5, 0.0, 7.5], v);
+/// ```
+/// ```
+/// # use stats::*;
+/// let data = "host,latency\na,12.5\nb,\nc,slow\n";
+/// match read_column(data.as_bytes(), Column::Name("latency"), Missing::Error) {
+///     Err(StatsError::MissingValue { line }) => assert_eq!(3, line),
+///     other => panic!("{:?}", other),
+/// }
+/// match read_column(data.as_bytes(), Column::Name("latency"), Missing::Skip) {
+///     Err(StatsError::Parse { line, field }) => assert_eq!((4, "slow".to_string()), (line, field)),
+///     other => panic!("{:?}", other),
+/// }
+/// assert!(read_column(data.as_bytes(), Column::Name("size"), Missing::Skip).is_err());
+/// ```
+pub fn read_column<R: Read>(
+    reader: R,
+    column: Column,
+    missing: Missing,
+) -> Result<Vec<f64>, StatsError> {
+    let mut csv = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+    let index = match column {
+        Column::Index(i) => i,
+        Column::Name(name) => csv
+            .headers()?
+            .iter()
+            .position(|h| h.trim() == name)
+            .ok_or_else(|| StatsError::MissingColumn(name.to_string()))?,
+    };
+    let mut values = Vec::new();
+    for record in csv.records() {
+        let record = record?;
+        let line = record.position().map_or(0, |p| p.line());
+        let field = record.get(index).unwrap_or("").trim();
+        if field.is_empty() {
+            match missing {
+                Missing::Error => return Err(StatsError::MissingValue { line }),
+                Missing::Skip => {}
+                Missing::Value(v) => values.push(v),
+            }
+            continue;
+        }
+        let value = field.parse().map_err(|_| StatsError::Parse {
+            line,
+            field: field.to_string(),
+        })?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Numbers in one column of the CSV file at `path`, as for
+/// `read_column`.
+pub fn read_column_path<P: AsRef<Path>>(
+    path: P,
+    column: Column,
+    missing: Missing,
+) -> Result<Vec<f64>, StatsError> {
+    read_column(std::fs::File::open(path)?, column, missing)
+}
@@ -0,0 +1,266 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Confidence intervals.
+
+use crate::dist::{Binomial, ChiSquare, Continuous, Discrete};
+use crate::special::{beta_inc, invert_cdf, normal_quantile, student_t_quantile};
+use crate::{bootstrap_ci, mean, median, sample_variance, sorted, BootstrapMethod};
+
+/// Point estimate of a quantity with a confidence interval
+/// around it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceInterval {
+    /// Point estimate.
+    pub estimate: f64,
+    /// Lower end of the interval.
+    pub lower: f64,
+    /// Upper end of the interval.
+    pub upper: f64,
+}
+
+/// Whether `confidence` is a usable confidence level.
+pub(crate) fn valid_confidence(confidence: f64) -> bool {
+    confidence > 0.0 && confidence < 1.0
+}
+
+/// Confidence interval for the mean at the given
+/// `confidence` level (such as 0.95). With `sigma` of
+/// `None` the interval uses the sample standard deviation
+/// and the t distribution; with a known population standard
+/// deviation it uses the normal distribution. The interval
+/// is undefined for a confidence level outside `(0, 1)`,
+/// for fewer than two values (one with a known `sigma`), or
+/// for a `sigma` that is not positive.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, mean_ci(&[1.0], 0.95, None));
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = mean_ci(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.95, None).unwrap();
+/// assert_eq!(3.0, ci.estimate);
+/// assert!((ci.lower - 1.036757).abs() < 1e-6);
+/// assert!((ci.upper - 4.963243).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = mean_ci(&[1.0, 2.0, 3.0, 6.0], 0.95, Some(2.0)).unwrap();
+/// assert!((ci.lower - (3.0 - 1.959964)).abs() < 1e-6);
+/// assert!((ci.upper - (3.0 + 1.959964)).abs() < 1e-6);
+/// ```
+pub fn mean_ci(nums: &[f64], confidence: f64, sigma: Option<f64>) -> Option<ConfidenceInterval> {
+    if !valid_confidence(confidence) || nums.is_empty() {
+        return None;
+    }
+    let n = nums.len() as f64;
+    let estimate = mean(nums)?;
+    let upper_tail = 0.5 + confidence / 2.0;
+    let margin = match sigma {
+        Some(sigma) if sigma > 0.0 => normal_quantile(upper_tail) * sigma / n.sqrt(),
+        Some(_) => return None,
+        None => {
+            let s = sample_variance(nums)?.sqrt();
+            student_t_quantile(upper_tail, n - 1.0) * s / n.sqrt()
+        }
+    };
+    Some(ConfidenceInterval {
+        estimate,
+        lower: estimate - margin,
+        upper: estimate + margin,
+    })
+}
+
+/// Method for `proportion_ci`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProportionMethod {
+    /// Wilson score interval: good coverage at any sample
+    /// size, and the usual recommendation.
+    Wilson,
+    /// Clopper–Pearson interval from the binomial
+    /// distribution: guaranteed coverage, but conservative.
+    ClopperPearson,
+    /// Normal-approximation (Wald) interval, clipped to
+    /// `[0, 1]`. Poor for small samples or proportions near
+    /// 0 or 1; provided for comparison with other tools.
+    Normal,
+}
+
+/// Confidence interval for a binomial proportion from
+/// `successes` out of `trials`, at the given `confidence`
+/// level. The interval is undefined for no trials, for more
+/// successes than trials, or for a confidence level outside
+/// `(0, 1)`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, proportion_ci(3, 2, 0.95, ProportionMethod::Wilson));
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = proportion_ci(8, 20, 0.95, ProportionMethod::Wilson).unwrap();
+/// assert_eq!(0.4, ci.estimate);
+/// assert!((ci.lower - 0.218807).abs() < 1e-6 && (ci.upper - 0.613418).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = proportion_ci(8, 20, 0.95, ProportionMethod::ClopperPearson).unwrap();
+/// assert!((ci.lower - 0.191190).abs() < 1e-6 && (ci.upper - 0.639457).abs() < 1e-6);
+/// let ci = proportion_ci(8, 20, 0.95, ProportionMethod::Normal).unwrap();
+/// assert!((ci.lower - 0.185297).abs() < 1e-6 && (ci.upper - 0.614703).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = proportion_ci(0, 10, 0.95, ProportionMethod::ClopperPearson).unwrap();
+/// assert_eq!(0.0, ci.lower);
+/// assert!((ci.upper - (1.0 - 0.025f64.powf(0.1))).abs() < 1e-12);
+/// ```
+pub fn proportion_ci(
+    successes: u64,
+    trials: u64,
+    confidence: f64,
+    method: ProportionMethod,
+) -> Option<ConfidenceInterval> {
+    if trials == 0 || successes > trials || !valid_confidence(confidence) {
+        return None;
+    }
+    let (x, n) = (successes as f64, trials as f64);
+    let estimate = x / n;
+    let alpha = 1.0 - confidence;
+    let z = normal_quantile(1.0 - alpha / 2.0);
+    let (lower, upper) = match method {
+        ProportionMethod::Wilson => {
+            let denominator = 1.0 + z * z / n;
+            let center = (estimate + z * z / (2.0 * n)) / denominator;
+            let half =
+                z * (estimate * (1.0 - estimate) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+            (center - half, center + half)
+        }
+        ProportionMethod::ClopperPearson => {
+            let beta_quantile = |p: f64, a: f64, b: f64| invert_cdf(|t| beta_inc(a, b, t), p, 0.0);
+            let lower = if successes == 0 {
+                0.0
+            } else {
+                beta_quantile(alpha / 2.0, x, n - x + 1.0)
+            };
+            let upper = if successes == trials {
+                1.0
+            } else {
+                beta_quantile(1.0 - alpha / 2.0, x + 1.0, n - x)
+            };
+            (lower, upper)
+        }
+        ProportionMethod::Normal => {
+            let half = z * (estimate * (1.0 - estimate) / n).sqrt();
+            ((estimate - half).max(0.0), (estimate + half).min(1.0))
+        }
+    };
+    Some(ConfidenceInterval {
+        estimate,
+        lower,
+        upper,
+    })
+}
+
+/// Number of resamples `median_ci` draws when it falls back
+/// to the bootstrap.
+pub const MEDIAN_CI_RESAMPLES: usize = 10_000;
+
+/// Confidence interval for the median at the given
+/// `confidence` level, between a pair of order statistics
+/// chosen from the binomial distribution so that coverage
+/// is at least the confidence level. A sample too small for
+/// any pair to reach that coverage gets a percentile
+/// bootstrap interval instead, from `MEDIAN_CI_RESAMPLES`
+/// resamples with seed 0. The estimate is `median`. The
+/// interval is undefined for an empty list or for a
+/// confidence level outside `(0, 1)`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, median_ci(&[], 0.95));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums: Vec<f64> = (1..=20).map(f64::from).collect();
+/// let ci = median_ci(&nums, 0.95).unwrap();
+/// assert_eq!((6.0, 10.0, 15.0), (ci.lower, ci.estimate, ci.upper));
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = median_ci(&[3.0, 1.0, 2.0], 0.95).unwrap();
+/// assert_eq!(2.0, ci.estimate);
+/// assert!(1.0 <= ci.lower && ci.upper <= 3.0);
+/// assert_eq!(ci, median_ci(&[3.0, 1.0, 2.0], 0.95).unwrap());
+/// ```
+pub fn median_ci(nums: &[f64], confidence: f64) -> Option<ConfidenceInterval> {
+    if nums.is_empty() || !valid_confidence(confidence) {
+        return None;
+    }
+    let n = nums.len();
+    let alpha = 1.0 - confidence;
+    // The number of values below the median is Binomial(n,
+    // 1/2); the k-th smallest value is the lower end.
+    let k = Binomial::new(n as u64, 0.5)?.inverse_cdf(alpha / 2.0)? as usize;
+    if k == 0 {
+        return bootstrap_ci(
+            nums,
+            median,
+            MEDIAN_CI_RESAMPLES,
+            confidence,
+            BootstrapMethod::Percentile,
+            0,
+        );
+    }
+    let values = sorted(nums);
+    Some(ConfidenceInterval {
+        estimate: median(nums)?,
+        lower: values[k - 1],
+        upper: values[n - k],
+    })
+}
+
+/// Confidence interval for the standard deviation at the
+/// given `confidence` level, from the chi-square
+/// distribution of the sample variance of normal data. The
+/// estimate is the sample (Bessel-corrected) standard
+/// deviation. The interval is undefined for fewer than two
+/// values or for a confidence level outside `(0, 1)`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, stddev_ci(&[1.0], 0.95));
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = stddev_ci(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.95).unwrap();
+/// assert!((ci.estimate - 2.5f64.sqrt()).abs() < 1e-15);
+/// assert!((ci.lower - 0.947313).abs() < 1e-6);
+/// assert!((ci.upper - 4.543490).abs() < 1e-6);
+/// ```
+pub fn stddev_ci(nums: &[f64], confidence: f64) -> Option<ConfidenceInterval> {
+    if !valid_confidence(confidence) {
+        return None;
+    }
+    let variance = sample_variance(nums)?;
+    let df = (nums.len() - 1) as f64;
+    let chi_square = ChiSquare::new(df)?;
+    let alpha = 1.0 - confidence;
+    let bound = |p: f64| (df * variance / chi_square.inverse_cdf(p)).sqrt();
+    Some(ConfidenceInterval {
+        estimate: variance.sqrt(),
+        lower: bound(1.0 - alpha / 2.0),
+        upper: bound(alpha / 2.0),
+    })
+}
@@ -3,13 +3,134 @@
 // Please see the file LICENSE in the source
 // distribution of this software for license terms.
 
-///! Functions to compute various statistics on a slice of
-///! floating-point numbers.
+//! Functions to compute various statistics on a slice of
+//! floating-point numbers.
+//!
+//! Functions that sort their input never panic on NaN:
+//! NaN values sort after all numbers, so they affect the
+//! result as the largest values would.
+//!
+//! Randomized functions never draw on hidden global or
+//! thread-local randomness: each takes an explicit seed
+//! (or, with the `rand` feature, a caller-supplied
+//! generator) and gives the same result for the same seed.
 
 /// Type of statistics function. If the statistic
 /// is ill-defined, `None` will be returned.
 pub type StatFn = fn(&[f64]) -> Option<f64>;
 
+/// Statistics by name, as the `stats` program accepts them
+/// (in the form `--name`).
+pub const STATISTICS: &[(&str, StatFn)] = &[
+    ("mean", mean),
+    ("stddev", stddev),
+    ("median", median),
+    ("median-interpolated", median_interpolated),
+    ("l2", l2),
+    ("mad", mad),
+    ("gini", gini),
+];
+
+/// Statistic with the given name in `STATISTICS`, if any.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let f = statistic("median").unwrap();
+/// assert_eq!(Some(2.0), f(&[3.0, 1.0, 2.0]));
+/// assert!(statistic("mode").is_none());
+/// ```
+pub fn statistic(name: &str) -> Option<StatFn> {
+    STATISTICS.iter().find(|(n, _)| *n == name).map(|(_, f)| *f)
+}
+
+/// Scale factor making the MAD a consistent estimator of
+/// the standard deviation of normal data.
+pub(crate) const MAD_SCALE: f64 = 1.4826;
+
+#[cfg(feature = "ndarray")]
+pub mod arrays;
+mod benford;
+mod categorical;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+mod columns;
+mod density;
+pub mod dist;
+mod ecdf;
+mod effect;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod finance;
+mod histogram;
+mod hypothesis;
+mod imputation;
+mod inequality;
+mod information;
+#[cfg(feature = "csv")]
+mod ingest;
+mod interval;
+#[cfg(feature = "nalgebra")]
+pub mod linalg;
+mod multiple;
+mod multivariate;
+mod nonparametric;
+mod normality;
+mod order;
+mod outliers;
+mod plot;
+mod power;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod render;
+mod resample;
+mod rng;
+mod robust;
+mod sampling;
+#[cfg(feature = "polars")]
+pub mod series;
+mod smoothing;
+mod special;
+mod summary;
+mod timeseries;
+pub mod v2;
+mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use benford::*;
+pub use categorical::*;
+pub use columns::*;
+pub use density::*;
+pub use ecdf::*;
+pub use effect::*;
+pub use error::*;
+pub use histogram::*;
+pub use hypothesis::*;
+pub use imputation::*;
+pub use inequality::*;
+pub use information::*;
+#[cfg(feature = "csv")]
+pub use ingest::*;
+pub use interval::*;
+pub use multiple::*;
+pub use multivariate::*;
+pub use nonparametric::*;
+pub use normality::*;
+pub use order::*;
+pub use outliers::*;
+pub use plot::*;
+pub use power::*;
+pub use resample::*;
+pub use robust::*;
+pub use sampling::*;
+pub use smoothing::*;
+pub use summary::*;
+pub use timeseries::*;
+pub use validation::*;
+
 /// Arithmetic mean of input values. The mean of an empty
 /// list is 0.0.
 ///
@@ -29,7 +150,7 @@ pub type StatFn = fn(&[f64]) -> Option<f64>;
 /// ```
 pub fn mean(nums: &[f64]) -> Option<f64> {
     let count = nums.len() as f64;
-    let sum = nums.iter().sum::<f64>() as f64;
+    let sum = nums.iter().sum::<f64>();
 
     if nums.is_empty() {
         Some(0.0)
@@ -61,7 +182,7 @@ pub fn stddev(nums: &[f64]) -> Option<f64> {
             let variance = nums
                 .iter()
                 .map(|value| {
-                    let v = *value as f64;
+                    let v = *value;
                     let difference = nums_mean - v;
                     difference.powf(2.0)
                 })
@@ -77,6 +198,11 @@ pub fn stddev(nums: &[f64]) -> Option<f64> {
 /// to the beginning to break ties. The median
 /// of an empty list is undefined.
 ///
+/// For an even number of values this is the lower of the
+/// two middle values, always one of the inputs; NumPy and
+/// R instead average the two, as `median_interpolated`
+/// does.
+///
 /// # Examples:
 ///
 /// ```
@@ -91,11 +217,23 @@ pub fn stddev(nums: &[f64]) -> Option<f64> {
 /// # use stats::*;
 /// assert_eq!(Some(0.5), median(&[0.0, 0.5, -1.0, 1.0, 2.0]));
 /// ```
+/// ```
+/// # use stats::*;
+/// // NaN sorts last rather than panicking.
+/// assert_eq!(Some(1.0), median(&[f64::NAN, 1.0, 0.0]));
+/// assert_eq!(Some(1.0), median(&[f64::NAN, 1.0]));
+/// assert!(quantile(&[f64::NAN, 1.0], 1.0).unwrap().is_nan());
+/// ```
+/// ```
+/// # use stats::*;
+/// // So does a computed NaN, which may have its sign bit set.
+/// let nan = std::hint::black_box(f64::INFINITY) - f64::INFINITY;
+/// assert_eq!(Some(2.0), median(&[nan, 1.0, 2.0, 3.0]));
+/// assert_eq!(Some(2.0), median(&[-f64::NAN, 1.0, 2.0, 3.0]));
+/// ```
 pub fn median(nums: &[f64]) -> Option<f64> {
     // Make a sorted copy of the input floats.
-    let mut nums = nums.to_owned();
-    // https://users.rust-lang.org/t/how-to-sort-a-vec-of-floats/2838/2
-    nums.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let nums = sorted(nums);
 
     let s = nums.len();
     if !nums.is_empty() {
@@ -105,9 +243,43 @@ pub fn median(nums: &[f64]) -> Option<f64> {
     }
 }
 
+/// Median value of input values, averaging the two middle
+/// values when there is an even number of them, as NumPy
+/// and R do. This is `quantile(nums, 0.5)`. The median of
+/// an empty list is undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, median_interpolated(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(0.25), median_interpolated(&[0.0, 0.5, -1.0, 1.0]));
+/// assert_eq!(Some(0.0), median(&[0.0, 0.5, -1.0, 1.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(0.5), median_interpolated(&[0.0, 0.5, -1.0, 1.0, 2.0]));
+/// ```
+pub fn median_interpolated(nums: &[f64]) -> Option<f64> {
+    let nums = sorted(nums);
+    let s = nums.len();
+    if nums.is_empty() {
+        None
+    } else {
+        Some((nums[(s - 1) / 2] + nums[s / 2]) / 2.0)
+    }
+}
+
 /// L2 norm (Euclidean norm) of input values. The L2
 /// norm of an empty list is 0.0.
 ///
+/// The squares are accumulated relative to the largest
+/// magnitude seen so far, so the result neither overflows
+/// for huge values nor underflows for tiny ones.
+///
 /// # Examples:
 ///
 /// ```
@@ -122,16 +294,121 @@ pub fn median(nums: &[f64]) -> Option<f64> {
 /// # use stats::*;
 /// assert_eq!(Some(8.0), l2(&[4.0, 4.0, 4.0, 4.0]));
 /// ```
+/// ```
+/// # use stats::*;
+/// let big = l2(&[3e200, 4e200]).unwrap();
+/// assert!((big / 5e200 - 1.0).abs() < 1e-15);
+/// let tiny = l2(&[3e-200, -4e-200]).unwrap();
+/// assert!((tiny / 5e-200 - 1.0).abs() < 1e-15);
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(f64::INFINITY), l2(&[f64::INFINITY, f64::NEG_INFINITY]));
+/// ```
 pub fn l2(nums: &[f64]) -> Option<f64> {
+    // Invariant: the sum of squares so far is
+    // scale² · scaled_sum.
+    let mut scale = 0.0f64;
+    let mut scaled_sum = 1.0;
+    for &x in nums {
+        if x == 0.0 {
+            continue;
+        }
+        let a = x.abs();
+        if a == f64::INFINITY {
+            return Some(f64::INFINITY);
+        }
+        if scale < a {
+            scaled_sum = 1.0 + scaled_sum * (scale / a).powi(2);
+            scale = a;
+        } else {
+            scaled_sum += (a / scale).powi(2);
+        }
+    }
+    Some(scale * scaled_sum.sqrt())
+}
+
+/// Quantile `q` of input values, for `q` in `[0, 1]`,
+/// interpolating linearly between the two nearest ranks.
+/// The quantile of an empty list, or for `q` outside
+/// `[0, 1]`, is undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, quantile(&[], 0.5));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(2.5), quantile(&[4.0, 1.0, 3.0, 2.0], 0.5));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(1.75), quantile(&[4.0, 1.0, 3.0, 2.0], 0.25));
+/// ```
+pub fn quantile(nums: &[f64], q: f64) -> Option<f64> {
+    if nums.is_empty() || !(0.0..=1.0).contains(&q) {
+        return None;
+    }
+    let nums = sorted(nums);
+    Some(sorted_quantile(&nums, q))
+}
+
+/// Ordering used to sort floats throughout the crate, so
+/// that NaN never panics: every NaN, whatever its sign bit,
+/// sorts after every number, and otherwise the IEEE 754
+/// total order applies, with -0.0 before 0.0.
+pub(crate) fn cmp_f64(a: &f64, b: &f64) -> std::cmp::Ordering {
+    a.is_nan().cmp(&b.is_nan()).then_with(|| a.total_cmp(b))
+}
+
+/// Sorted copy of the input floats.
+pub(crate) fn sorted(nums: &[f64]) -> Vec<f64> {
     let mut nums = nums.to_owned();
+    nums.sort_by(cmp_f64);
+    nums
+}
 
-    if nums.is_empty() {
-        Some(0.0)
-    } else {
-        let mut sum: f64 = 0.0;
-        for numbers in &mut nums {
-            sum += (*numbers).powf(2.0);
-        }
-        Some(sum.sqrt())
+/// Quantile `q` of an already-sorted, non-empty slice.
+pub(crate) fn sorted_quantile(nums: &[f64], q: f64) -> f64 {
+    let h = (nums.len() - 1) as f64 * q;
+    let lo = h.floor() as usize;
+    let hi = h.ceil() as usize;
+    nums[lo] + (h - lo as f64) * (nums[hi] - nums[lo])
+}
+
+/// Sample (Bessel-corrected) variance of input values,
+/// undefined for fewer than two values.
+pub(crate) fn sample_variance(nums: &[f64]) -> Option<f64> {
+    if nums.len() < 2 {
+        return None;
     }
+    let m = mean(nums)?;
+    let ss = nums.iter().map(|x| (x - m).powi(2)).sum::<f64>();
+    Some(ss / (nums.len() - 1) as f64)
+}
+
+/// Median absolute deviation of input values: the median of
+/// the absolute differences from the median. The MAD of an
+/// empty list is undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, mad(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(0.0), mad(&[3.0, 3.0, 3.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(1.0), mad(&[1.0, 2.0, 3.0, 4.0, 100.0]));
+/// ```
+pub fn mad(nums: &[f64]) -> Option<f64> {
+    let m = median(nums)?;
+    let deviations: Vec<f64> = nums.iter().map(|v| (v - m).abs()).collect();
+    median(&deviations)
 }
@@ -0,0 +1,148 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of `nalgebra` vectors and matrices. A data
+//! matrix has one row per observation and one column per
+//! variable, so its columns play the role of the columns
+//! taken by the multivariate functions.
+
+use crate::multivariate::distance;
+use crate::{CorrelationMethod, Estimator, Pca};
+use nalgebra::{DMatrix, DVector};
+
+/// Columns of a data matrix, which `nalgebra` stores
+/// contiguously in column-major order.
+fn columns(data: &DMatrix<f64>) -> Vec<&[f64]> {
+    if data.nrows() == 0 {
+        return vec![&[]; data.ncols()];
+    }
+    data.as_slice().chunks(data.nrows()).collect()
+}
+
+/// Square matrix from the rows of a nested `Vec`.
+fn to_matrix(rows: Vec<Vec<f64>>) -> DMatrix<f64> {
+    let n = rows.len();
+    DMatrix::from_fn(n, n, |i, j| rows[i][j])
+}
+
+/// L2 norm of a vector, as for `l2`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::linalg::*;
+/// use nalgebra::DVector;
+/// assert_eq!(Some(5.0), vector_l2(&DVector::from_vec(vec![-3.0, 4.0])));
+/// ```
+pub fn vector_l2(v: &DVector<f64>) -> Option<f64> {
+    crate::l2(v.as_slice())
+}
+
+/// Euclidean distance between two vectors, undefined for
+/// vectors of differing length.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::linalg::*;
+/// use nalgebra::DVector;
+/// let a = DVector::from_vec(vec![1.0, 1.0]);
+/// let b = DVector::from_vec(vec![4.0, 5.0]);
+/// assert_eq!(Some(5.0), vector_distance(&a, &b));
+/// assert_eq!(None, vector_distance(&a, &DVector::from_vec(vec![1.0])));
+/// ```
+pub fn vector_distance(a: &DVector<f64>, b: &DVector<f64>) -> Option<f64> {
+    if a.len() != b.len() {
+        return None;
+    }
+    Some(distance(a.as_slice(), b.as_slice()))
+}
+
+/// Covariance matrix of the columns of a data matrix, as
+/// for `covariance_matrix`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::linalg::*;
+/// use nalgebra::DMatrix;
+/// use stats::Estimator;
+/// let data = DMatrix::from_row_slice(3, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+/// let c = matrix_covariance(&data, Estimator::Sample).unwrap();
+/// assert_eq!(DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 4.0]), c);
+/// assert_eq!(None, matrix_covariance(&DMatrix::zeros(0, 2), Estimator::Sample));
+/// ```
+pub fn matrix_covariance(data: &DMatrix<f64>, estimator: Estimator) -> Option<DMatrix<f64>> {
+    crate::covariance_matrix(&columns(data), estimator).map(to_matrix)
+}
+
+/// Correlation matrix of the columns of a data matrix, as
+/// for `correlation_matrix`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::linalg::*;
+/// use nalgebra::DMatrix;
+/// use stats::CorrelationMethod;
+/// let data = DMatrix::from_row_slice(3, 2, &[1.0, 3.0, 2.0, 2.0, 3.0, 1.0]);
+/// let r = matrix_correlation(&data, CorrelationMethod::Pearson).unwrap();
+/// let expected = DMatrix::from_row_slice(2, 2, &[1.0, -1.0, -1.0, 1.0]);
+/// assert!((r - expected).abs().max() < 1e-12);
+/// ```
+pub fn matrix_correlation(data: &DMatrix<f64>, method: CorrelationMethod) -> Option<DMatrix<f64>> {
+    crate::correlation_matrix(&columns(data), method).map(to_matrix)
+}
+
+/// Outcome of a principal component analysis of a data
+/// matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixPca {
+    /// Unit-length direction of each component, one column
+    /// per component and one row per variable.
+    pub loadings: DMatrix<f64>,
+    /// Variance of the data along each component.
+    pub explained_variance: DVector<f64>,
+    /// Fraction of the total variance along each component.
+    pub explained_variance_ratio: DVector<f64>,
+    /// Coordinates of the centered data along each
+    /// component, one row per observation and one column
+    /// per component.
+    pub scores: DMatrix<f64>,
+}
+
+/// Principal component analysis of the columns of a data
+/// matrix, as for `pca`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::linalg::*;
+/// use nalgebra::{DMatrix, DVector};
+/// let data = DMatrix::from_row_slice(4, 2, &[2.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, -1.0]);
+/// let p = matrix_pca(&data, 1).unwrap();
+/// assert_eq!(DMatrix::from_row_slice(2, 1, &[1.0, 0.0]), p.loadings);
+/// assert_eq!(DVector::from_vec(vec![0.8]), p.explained_variance_ratio);
+/// assert_eq!(DMatrix::from_row_slice(4, 1, &[2.0, 0.0, -2.0, 0.0]), p.scores);
+/// ```
+/// ```
+/// # use stats::linalg::*;
+/// use nalgebra::DMatrix;
+/// assert_eq!(None, matrix_pca(&DMatrix::from_row_slice(1, 3, &[1.0, 2.0, 3.0]), 1));
+/// ```
+pub fn matrix_pca(data: &DMatrix<f64>, n_components: usize) -> Option<MatrixPca> {
+    let Pca {
+        loadings,
+        explained_variance,
+        explained_variance_ratio,
+        scores,
+    } = crate::pca(&columns(data), n_components)?;
+    Some(MatrixPca {
+        loadings: DMatrix::from_fn(data.ncols(), n_components, |i, c| loadings[c][i]),
+        explained_variance: DVector::from_vec(explained_variance),
+        explained_variance_ratio: DVector::from_vec(explained_variance_ratio),
+        scores: DMatrix::from_fn(data.nrows(), n_components, |i, c| scores[c][i]),
+    })
+}
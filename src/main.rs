@@ -3,56 +3,186 @@
 // Please see the file LICENSE in the source
 // distribution of this software for license terms.
 
+//! Compute statistics on whitespace-separated numbers read
+//! from a file or standard input.
+
+use stats::{json_number, render, Histogram, StatFn, Summary};
+use std::io::{BufRead, BufReader, Read};
 use std::process::exit;
 
-///! Compute a statistic on numbers presented one-per-line on
-///! standard input.
+/// Number of histogram bins unless `--histogram=BINS` says
+/// otherwise.
+const DEFAULT_BINS: usize = 10;
+
+/// Width of the longest histogram bar, in characters.
+const HISTOGRAM_WIDTH: usize = 40;
 
 /// Report proper usage and exit.
 fn usage() -> ! {
-    eprintln!("stats: usage: stats [--mean|--stddev|--median|--l2]");
+    let names: Vec<String> = stats::STATISTICS
+        .iter()
+        .map(|(name, _)| format!("--{}", name))
+        .collect();
+    eprintln!(
+        "stats: usage: stats [{}|--count|--min|--max|--pN|--histogram[=BINS]]... [--json] [FILE]",
+        names.join("|")
+    );
     exit(1);
 }
 
+/// How a requested statistic is computed.
+enum Compute {
+    /// By a statistic from the library registry.
+    Slice(StatFn),
+    /// As this percentile, from `--pN`.
+    Percentile(f64),
+    /// From the running summary.
+    Running(fn(&Summary) -> Option<f64>),
+}
+
+/// A statistic requested on the command line.
+struct Request {
+    /// Flag naming the statistic, without its `--`.
+    name: String,
+    compute: Compute,
+}
+
+impl Request {
+    /// The statistic named by a flag, without its `--`.
+    fn new(name: &str) -> Option<Request> {
+        let compute = match name {
+            "count" => Compute::Running(|s| Some(s.count() as f64)),
+            "min" => Compute::Running(Summary::min),
+            "max" => Compute::Running(Summary::max),
+            _ => match stats::statistic(name) {
+                Some(f) => Compute::Slice(f),
+                None => {
+                    let p = name.strip_prefix('p')?.parse::<f64>().ok()?;
+                    if !(0.0..=100.0).contains(&p) {
+                        return None;
+                    }
+                    Compute::Percentile(p)
+                }
+            },
+        };
+        Some(Request {
+            name: name.to_string(),
+            compute,
+        })
+    }
+
+    /// Value of the statistic for the input.
+    fn value(&self, nums: &[f64], summary: &Summary) -> Option<f64> {
+        match self.compute {
+            Compute::Slice(f) => f(nums),
+            Compute::Percentile(p) => stats::quantile(nums, p / 100.0),
+            Compute::Running(f) => f(summary),
+        }
+    }
+
+    /// Whether the input values must be kept in memory.
+    fn needs_values(&self) -> bool {
+        !matches!(self.compute, Compute::Running(_))
+    }
+}
+
+/// Print the results as one JSON object, with a field per
+/// requested statistic (`null` where undefined) and, if
+/// requested, a `histogram` field (`null` for no input).
+fn print_json(
+    requests: &[Request],
+    nums: &[f64],
+    summary: &Summary,
+    hist: Option<Option<Histogram>>,
+) {
+    let mut fields: Vec<String> = requests
+        .iter()
+        .map(|r| format!("{:?}:{}", r.name, json_number(r.value(nums, summary))))
+        .collect();
+    if let Some(hist) = hist {
+        let value = hist.map_or("null".to_string(), |h| h.to_json());
+        fields.push(format!(r#""histogram":{}"#, value));
+    }
+    println!("{{{}}}", fields.join(","));
+}
+
 /// Do the computation.
 fn main() {
-    // Process the argument.
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() != 2 {
+    // Process the arguments.
+    let mut requests = Vec::new();
+    let mut bins = None;
+    let mut json = false;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        if let Some(flag) = arg.strip_prefix("--") {
+            if flag == "json" {
+                json = true;
+            } else if flag == "histogram" {
+                bins = Some(DEFAULT_BINS);
+            } else if let Some(n) = flag.strip_prefix("histogram=") {
+                bins = Some(n.parse().ok().filter(|&n| n > 0).unwrap_or_else(|| usage()));
+            } else {
+                requests.push(Request::new(flag).unwrap_or_else(|| usage()));
+            }
+        } else if path.is_none() {
+            path = Some(arg);
+        } else {
+            usage();
+        }
+    }
+    if requests.is_empty() && bins.is_none() {
         usage();
     }
-    let target = &args[1];
-    let argdescs: &[(&str, stats::StatFn)] = &[
-        ("--mean", stats::mean),
-        ("--stddev", stats::stddev),
-        ("--median", stats::median),
-        ("--l2", stats::l2),
-    ];
-    let stat = argdescs
-        .iter()
-        .find(|(a, _)| a == target)
-        .unwrap_or_else(|| usage())
-        .1;
-
-    // Read the input.
-    use std::io::BufRead;
-    let nums: Vec<f64> = std::io::stdin()
-        .lock()
-        .lines()
-        .map(|s| {
-            let s = s.unwrap_or_else(|e| {
-                eprintln!("error reading input: {}", e);
-                exit(-1);
-            });
-            s.parse::<f64>().unwrap_or_else(|e| {
+
+    // Read the input, keeping the values only if needed.
+    let input: Box<dyn Read> = match &path {
+        Some(path) => Box::new(std::fs::File::open(path).unwrap_or_else(|e| {
+            eprintln!("error opening {}: {}", path, e);
+            exit(-1);
+        })),
+        None => Box::new(std::io::stdin()),
+    };
+    let keep = bins.is_some() || requests.iter().any(Request::needs_values);
+    let mut summary = Summary::new();
+    let mut nums = Vec::new();
+    for line in BufReader::new(input).lines() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("error reading input: {}", e);
+            exit(-1);
+        });
+        for s in line.split_whitespace() {
+            let x = s.parse::<f64>().unwrap_or_else(|e| {
                 eprintln!("error parsing number {}: {}", s, e);
                 exit(-1);
-            })
-        })
-        .collect();
+            });
+            summary.push(x);
+            if keep {
+                nums.push(x);
+            }
+        }
+    }
 
-    // Run the stat, show the result if any.
-    if let Some(result) = stat(&nums) {
-        println!("{}", result);
+    // Show the results.
+    let hist = bins.and_then(|bins| Histogram::new(&nums, bins));
+    if json {
+        print_json(&requests, &nums, &summary, bins.map(|_| hist));
+        return;
+    }
+    // A lone statistic is shown bare, and only if defined;
+    // several are labeled one per line.
+    if let [only] = requests.as_slice() {
+        if let Some(result) = only.value(&nums, &summary) {
+            println!("{}", result);
+        }
+    } else {
+        for r in &requests {
+            match r.value(&nums, &summary) {
+                Some(result) => println!("{}\t{}", r.name, result),
+                None => println!("{}\tundefined", r.name),
+            }
+        }
+    }
+    if let Some(hist) = hist {
+        print!("{}", render::histogram(&hist, HISTOGRAM_WIDTH));
     }
 }
@@ -0,0 +1,72 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Corrections for testing many hypotheses at once.
+//! Adjusted p-values are returned in the order of the
+//! inputs and can be compared directly against the
+//! significance level.
+
+use crate::cmp_f64;
+
+/// Bonferroni-adjusted p-values: each multiplied by the
+/// number of tests, capped at 1. Controls the family-wise
+/// error rate.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(vec![0.03, 1.0, 0.75], bonferroni(&[0.01, 0.5, 0.25]));
+/// ```
+pub fn bonferroni(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len() as f64;
+    p_values.iter().map(|p| (p * m).min(1.0)).collect()
+}
+
+/// Benjamini–Hochberg adjusted p-values ("q-values"),
+/// controlling the false discovery rate: the `i`-th smallest
+/// of `m` p-values is scaled by `m / i`, then made monotone
+/// from the largest down and capped at 1.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(benjamini_hochberg(&[]).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let q = benjamini_hochberg(&[0.01, 0.04, 0.03, 0.5]);
+/// let expected = [0.04, 0.04 * 4.0 / 3.0, 0.04 * 4.0 / 3.0, 0.5];
+/// assert!(q.iter().zip(&expected).all(|(q, e)| (q - e).abs() < 1e-12));
+/// ```
+pub fn benjamini_hochberg(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&i, &j| cmp_f64(&p_values[i], &p_values[j]));
+    let mut adjusted = vec![0.0; m];
+    let mut running = 1.0f64;
+    for (rank, &i) in order.iter().enumerate().rev() {
+        running = running.min(p_values[i] * m as f64 / (rank + 1) as f64);
+        adjusted[i] = running;
+    }
+    adjusted
+}
+
+/// Which hypotheses to reject at significance level
+/// `alpha`, given adjusted p-values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let p = [0.01, 0.04, 0.03, 0.5];
+/// assert_eq!(vec![true, false, false, false], reject(&bonferroni(&p), 0.05));
+/// assert_eq!(vec![true, false, false, false], reject(&benjamini_hochberg(&p), 0.05));
+/// assert_eq!(vec![true, true, true, false], reject(&benjamini_hochberg(&p), 0.06));
+/// ```
+pub fn reject(adjusted: &[f64], alpha: f64) -> Vec<bool> {
+    adjusted.iter().map(|&p| p <= alpha).collect()
+}
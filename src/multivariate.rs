@@ -0,0 +1,578 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of multivariate data, given as columns of
+//! equal length: one column per variable, one row per
+//! observation. Location estimates for point clouds take
+//! one slice of coordinates per point instead.
+
+use crate::cmp_f64;
+use crate::nonparametric::ranks;
+
+/// Divisor for sums of squared deviations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Estimator {
+    /// Divide by `n`, as `stddev` does.
+    Population,
+    /// Divide by `n - 1` (Bessel's correction).
+    Sample,
+}
+
+/// Columns centered on their means, provided they are
+/// non-empty and of equal length.
+fn centered(columns: &[&[f64]]) -> Option<Vec<Vec<f64>>> {
+    let n = columns.first()?.len();
+    if n == 0 || columns.iter().any(|c| c.len() != n) {
+        return None;
+    }
+    Some(
+        columns
+            .iter()
+            .map(|c| {
+                let m = c.iter().sum::<f64>() / n as f64;
+                c.iter().map(|x| x - m).collect()
+            })
+            .collect(),
+    )
+}
+
+/// Matrix of cross products of centered columns divided by
+/// `divisor`.
+fn cross_products(centered: &[Vec<f64>], divisor: f64) -> Vec<Vec<f64>> {
+    let k = centered.len();
+    let mut matrix = vec![vec![0.0; k]; k];
+    for i in 0..k {
+        for j in 0..=i {
+            let sum = centered[i]
+                .iter()
+                .zip(&centered[j])
+                .map(|(a, b)| a * b)
+                .sum::<f64>();
+            matrix[i][j] = sum / divisor;
+            matrix[j][i] = matrix[i][j];
+        }
+    }
+    matrix
+}
+
+/// Covariance matrix of the columns: entry `[i][j]` is the
+/// covariance of columns `i` and `j`, and the diagonal
+/// holds the variances. The matrix is undefined for no
+/// columns, for empty or unequal-length columns, or for a
+/// single row with the `Sample` estimator.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, covariance_matrix(&[&[1.0, 2.0], &[1.0]], Estimator::Sample));
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [1.0, 2.0, 3.0, 4.0];
+/// let y = [2.0, 4.0, 6.0, 8.0];
+/// let z = [4.0, 3.0, 2.0, 1.0];
+/// let cov = covariance_matrix(&[&x, &y, &z], Estimator::Sample).unwrap();
+/// let v = 5.0 / 3.0;
+/// assert_eq!(vec![
+///     vec![v, 2.0 * v, -v],
+///     vec![2.0 * v, 4.0 * v, -2.0 * v],
+///     vec![-v, -2.0 * v, v],
+/// ], cov);
+/// ```
+/// ```
+/// # use stats::*;
+/// let cov = covariance_matrix(&[&[1.0, -2.0]], Estimator::Population).unwrap();
+/// assert_eq!(vec![vec![2.25]], cov);
+/// ```
+pub fn covariance_matrix(columns: &[&[f64]], estimator: Estimator) -> Option<Vec<Vec<f64>>> {
+    let centered = centered(columns)?;
+    let n = centered[0].len();
+    let divisor = match estimator {
+        Estimator::Population => n as f64,
+        Estimator::Sample if n > 1 => (n - 1) as f64,
+        Estimator::Sample => return None,
+    };
+    Some(cross_products(&centered, divisor))
+}
+
+/// Kind of correlation coefficient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationMethod {
+    /// Pearson's product-moment correlation, measuring
+    /// linear association.
+    Pearson,
+    /// Spearman's rank correlation: Pearson's correlation of
+    /// the ranks, measuring monotone association.
+    Spearman,
+}
+
+/// Correlation matrix of the columns: entry `[i][j]` is the
+/// correlation of columns `i` and `j`, with ones on the
+/// diagonal. All pairs share one centering pass over each
+/// column. The matrix is undefined for no columns, for
+/// empty or unequal-length columns, or for a constant
+/// column.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, correlation_matrix(&[&[1.0, 2.0], &[3.0, 3.0]], CorrelationMethod::Pearson));
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [1.0, 2.0, 3.0, 4.0];
+/// let y = [2.0, 4.0, 6.0, 8.0];
+/// let z = [1.0, 3.0, 2.0, 4.0];
+/// let r = correlation_matrix(&[&x, &y, &z], CorrelationMethod::Pearson).unwrap();
+/// assert_eq!(1.0, r[0][0]);
+/// assert!((r[0][1] - 1.0).abs() < 1e-15);
+/// assert!((r[0][2] - 0.8).abs() < 1e-15 && r[2][0] == r[0][2]);
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [1.0, 2.0, 3.0, 4.0];
+/// let cubes = [1.0, 8.0, 27.0, 64.0];
+/// let r = correlation_matrix(&[&x, &cubes], CorrelationMethod::Spearman).unwrap();
+/// assert!((r[0][1] - 1.0).abs() < 1e-15);
+/// let r = correlation_matrix(&[&x, &cubes], CorrelationMethod::Pearson).unwrap();
+/// assert!(r[0][1] < 0.99);
+/// ```
+pub fn correlation_matrix(columns: &[&[f64]], method: CorrelationMethod) -> Option<Vec<Vec<f64>>> {
+    let centered = match method {
+        CorrelationMethod::Pearson => centered(columns)?,
+        CorrelationMethod::Spearman => {
+            let ranked: Vec<Vec<f64>> = columns.iter().map(|c| ranks(c).0).collect();
+            let ranked: Vec<&[f64]> = ranked.iter().map(|c| c.as_slice()).collect();
+            centered(&ranked)?
+        }
+    };
+    let mut matrix = cross_products(&centered, 1.0);
+    let scale: Vec<f64> = (0..matrix.len()).map(|i| matrix[i][i].sqrt()).collect();
+    if scale.contains(&0.0) {
+        return None;
+    }
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, r) in row.iter_mut().enumerate() {
+            *r = if i == j {
+                1.0
+            } else {
+                (*r / (scale[i] * scale[j])).clamp(-1.0, 1.0)
+            };
+        }
+    }
+    Some(matrix)
+}
+
+/// Most sweeps `symmetric_eigen` makes before giving up on
+/// convergence.
+const JACOBI_SWEEPS: usize = 100;
+
+/// Eigenvalues and unit eigenvectors of a symmetric matrix
+/// by the cyclic Jacobi method, sorted by decreasing
+/// eigenvalue. Each eigenvector is signed so that its
+/// largest-magnitude entry is positive.
+fn symmetric_eigen(matrix: &[Vec<f64>]) -> Vec<(f64, Vec<f64>)> {
+    let k = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut v: Vec<Vec<f64>> = (0..k)
+        .map(|i| (0..k).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let norm = a.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+    for _ in 0..JACOBI_SWEEPS {
+        let off = (0..k)
+            .flat_map(|i| (0..k).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum::<f64>()
+            .sqrt();
+        if off <= 1e-15 * norm {
+            break;
+        }
+        for p in 0..k {
+            for q in p + 1..k {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // Rotate in the (p, q) plane to zero a[p][q].
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let t = if theta == 0.0 { 1.0 } else { t };
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                let rotate = |x: &mut f64, y: &mut f64| {
+                    let (xv, yv) = (*x, *y);
+                    *x = c * xv - s * yv;
+                    *y = s * xv + c * yv;
+                };
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (head, tail) = row.split_at_mut(q);
+                    rotate(&mut head[p], &mut tail[0]);
+                }
+                let (head, tail) = a.split_at_mut(q);
+                for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                    rotate(x, y);
+                }
+            }
+        }
+    }
+    let mut pairs: Vec<(f64, Vec<f64>)> = (0..k)
+        .map(|j| {
+            let mut vector: Vec<f64> = v.iter().map(|row| row[j]).collect();
+            let largest = vector
+                .iter()
+                .cloned()
+                .fold(0.0, |m: f64, x| if x.abs() > m.abs() { x } else { m });
+            if largest < 0.0 {
+                vector.iter_mut().for_each(|x| *x = -*x);
+            }
+            (a[j][j], vector)
+        })
+        .collect();
+    pairs.sort_by(|x, y| cmp_f64(&y.0, &x.0));
+    pairs
+}
+
+/// Outcome of a principal component analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pca {
+    /// Unit-length direction of each component, with one
+    /// entry per input column.
+    pub loadings: Vec<Vec<f64>>,
+    /// Variance of the data along each component.
+    pub explained_variance: Vec<f64>,
+    /// Fraction of the total variance along each component.
+    pub explained_variance_ratio: Vec<f64>,
+    /// Coordinates of the centered data along each
+    /// component: `scores[c][i]` is the score of row `i` on
+    /// component `c`.
+    pub scores: Vec<Vec<f64>>,
+}
+
+/// Principal component analysis of the columns, keeping the
+/// first `n_components` components in order of decreasing
+/// variance. Variances use the `Sample` estimator. The
+/// analysis is undefined for no components or more
+/// components than columns, when the sample covariance
+/// matrix is undefined, or for constant data.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, pca(&[&[1.0, 2.0, 3.0]], 2));
+/// ```
+/// ```
+/// # use stats::*;
+/// // Points close to the line y = x.
+/// let x = [1.0, 2.0, 3.0, 4.0];
+/// let y = [1.1, 1.9, 3.1, 3.9];
+/// let p = pca(&[&x, &y], 2).unwrap();
+/// let h = 0.5f64.sqrt();
+/// assert!((p.loadings[0][0] - h).abs() < 0.05 && (p.loadings[0][1] - h).abs() < 0.05);
+/// assert!(p.explained_variance_ratio[0] > 0.99);
+/// let total: f64 = p.explained_variance_ratio.iter().sum();
+/// assert!((total - 1.0).abs() < 1e-12);
+/// assert_eq!(4, p.scores[0].len());
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [2.0, 0.0, -2.0, 0.0];
+/// let y = [0.0, 1.0, 0.0, -1.0];
+/// let p = pca(&[&x, &y], 1).unwrap();
+/// assert_eq!(vec![vec![1.0, 0.0]], p.loadings);
+/// assert_eq!(vec![8.0 / 3.0], p.explained_variance);
+/// assert_eq!(vec![0.8], p.explained_variance_ratio);
+/// assert_eq!(vec![vec![2.0, 0.0, -2.0, 0.0]], p.scores);
+/// ```
+pub fn pca(columns: &[&[f64]], n_components: usize) -> Option<Pca> {
+    if n_components == 0 || n_components > columns.len() {
+        return None;
+    }
+    let centered = centered(columns)?;
+    let n = centered[0].len();
+    if n < 2 {
+        return None;
+    }
+    let covariance = cross_products(&centered, (n - 1) as f64);
+    let total = (0..covariance.len()).map(|i| covariance[i][i]).sum::<f64>();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut eigen = symmetric_eigen(&covariance);
+    eigen.truncate(n_components);
+    let scores = eigen
+        .iter()
+        .map(|(_, loading)| {
+            (0..n)
+                .map(|i| loading.iter().zip(&centered).map(|(l, c)| l * c[i]).sum())
+                .collect()
+        })
+        .collect();
+    // Rounding can leave tiny negative eigenvalues.
+    let explained_variance: Vec<f64> = eigen.iter().map(|(value, _)| value.max(0.0)).collect();
+    Some(Pca {
+        explained_variance_ratio: explained_variance.iter().map(|v| v / total).collect(),
+        explained_variance,
+        loadings: eigen.into_iter().map(|(_, loading)| loading).collect(),
+        scores,
+    })
+}
+
+/// Coordinates per point, provided there are points and
+/// they all have the same non-zero dimension.
+fn dimension(points: &[&[f64]]) -> Option<usize> {
+    let d = points.first()?.len();
+    if d == 0 || points.iter().any(|p| p.len() != d) {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+/// Euclidean distance between two points.
+pub(crate) fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Centroid of the points: their coordinate-wise mean. The
+/// centroid is undefined for no points, or for points of
+/// zero or differing dimension.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, centroid(&[&[1.0, 2.0], &[3.0]]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let c = centroid(&[&[0.0, 0.0], &[2.0, 0.0], &[1.0, 3.0]]).unwrap();
+/// assert_eq!(vec![1.0, 1.0], c);
+/// ```
+pub fn centroid(points: &[&[f64]]) -> Option<Vec<f64>> {
+    let d = dimension(points)?;
+    let n = points.len() as f64;
+    Some(
+        (0..d)
+            .map(|j| points.iter().map(|p| p[j]).sum::<f64>() / n)
+            .collect(),
+    )
+}
+
+/// Most iterations `geometric_median` makes before
+/// returning its current estimate.
+pub const WEISZFELD_ITERATIONS: usize = 1000;
+
+/// Geometric median of the points: the point minimizing the
+/// sum of Euclidean distances to them. It is a robust
+/// multivariate location estimate, unaffected by moving a
+/// minority of the points arbitrarily far. Computed by
+/// Weiszfeld's algorithm with the Vardi–Zhang correction at
+/// data points, stopping when an iteration moves less than
+/// `tolerance`. The median is undefined for a tolerance
+/// that is not positive, or when the centroid is.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, geometric_median(&[&[1.0]], 0.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let points: [&[f64]; 3] = [&[0.0], &[1.0], &[10.0]];
+/// let m = geometric_median(&points, 1e-12).unwrap();
+/// assert!((m[0] - 1.0).abs() < 1e-9);
+/// ```
+/// ```
+/// # use stats::*;
+/// let points: [&[f64]; 5] = [&[0.0, 0.0], &[2.0, 0.0], &[0.0, 2.0], &[2.0, 2.0], &[1e6, 1e6]];
+/// let m = geometric_median(&points, 1e-12).unwrap();
+/// // The far point pulls the median only to 1 + 1 / √3.
+/// let expected = 1.0 + 1.0 / 3f64.sqrt();
+/// assert!((m[0] - expected).abs() < 1e-9 && (m[1] - expected).abs() < 1e-9);
+/// assert!(centroid(&points).unwrap()[0] > 1e5);
+/// ```
+pub fn geometric_median(points: &[&[f64]], tolerance: f64) -> Option<Vec<f64>> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return None;
+    }
+    let mut y = centroid(points)?;
+    let d = y.len();
+    for _ in 0..WEISZFELD_ITERATIONS {
+        let mut weighted = vec![0.0; d];
+        let mut weights = 0.0;
+        let mut coincident = 0.0;
+        for p in points {
+            let dist = distance(p, &y);
+            if dist == 0.0 {
+                coincident += 1.0;
+                continue;
+            }
+            for (w, x) in weighted.iter_mut().zip(p.iter()) {
+                *w += x / dist;
+            }
+            weights += 1.0 / dist;
+        }
+        if weights == 0.0 {
+            // Every point coincides with the estimate.
+            return Some(y);
+        }
+        let t: Vec<f64> = weighted.iter().map(|w| w / weights).collect();
+        let next = if coincident == 0.0 {
+            t
+        } else {
+            // At a data point, move only if the pull of the
+            // other points outweighs the coincident ones.
+            let pull = distance(&t, &y) * weights;
+            let step = (1.0 - coincident / pull).max(0.0);
+            y.iter().zip(&t).map(|(y, t)| y + step * (t - y)).collect()
+        };
+        let moved = distance(&next, &y);
+        y = next;
+        if moved < tolerance {
+            break;
+        }
+    }
+    Some(y)
+}
+
+/// Kind of whitening transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Whitening {
+    /// Rotate onto the principal components, then scale
+    /// each to unit variance.
+    Pca,
+    /// Zero-phase (Mahalanobis) whitening: the PCA
+    /// transform rotated back, so the output stays as close
+    /// as possible to the input.
+    Zca,
+}
+
+/// Affine transform that centers multivariate data and
+/// decorrelates it to unit variance, fitted by `whiten`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Whitener {
+    /// Column means of the fitted data.
+    pub mean: Vec<f64>,
+    /// Linear map applied after centering: output column
+    /// `i` is `Σ_j matrix[i][j] * (column j - mean[j])`.
+    pub matrix: Vec<Vec<f64>>,
+}
+
+impl Whitener {
+    /// Transform columns of new data with the fitted
+    /// transform. The result is undefined unless there is
+    /// one column per fitted column, all of equal length.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let x = [1.0, 2.0, 3.0, 4.0];
+    /// let y = [2.0, 1.0, 4.0, 3.0];
+    /// let (w, _) = whiten(&[&x, &y], Whitening::Zca).unwrap();
+    /// assert_eq!(None, w.apply(&[&[1.0]]));
+    /// let out = w.apply(&[&[2.5], &[2.5]]).unwrap();
+    /// assert_eq!(vec![vec![0.0], vec![0.0]], out);
+    /// ```
+    pub fn apply(&self, columns: &[&[f64]]) -> Option<Vec<Vec<f64>>> {
+        if columns.len() != self.mean.len() {
+            return None;
+        }
+        let n = columns[0].len();
+        if columns.iter().any(|c| c.len() != n) {
+            return None;
+        }
+        let transformed = self
+            .matrix
+            .iter()
+            .map(|row| {
+                (0..n)
+                    .map(|i| {
+                        row.iter()
+                            .zip(columns)
+                            .zip(&self.mean)
+                            .map(|((w, c), m)| w * (c[i] - m))
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        Some(transformed)
+    }
+}
+
+/// Center and decorrelate the columns so that their sample
+/// covariance matrix is the identity, returning the fitted
+/// transform along with the transformed columns. The
+/// transform is undefined when the sample covariance matrix
+/// is undefined or singular, as for a constant column or a
+/// column that is a linear combination of others.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, whiten(&[&[1.0, 2.0], &[2.0, 4.0]], Whitening::Pca));
+/// ```
+/// ```
+/// # use stats::*;
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let y = [2.0, 3.0, 3.0, 6.0, 5.0];
+/// for method in [Whitening::Pca, Whitening::Zca].iter() {
+///     let (_, out) = whiten(&[&x, &y], *method).unwrap();
+///     let out: Vec<&[f64]> = out.iter().map(|c| c.as_slice()).collect();
+///     let cov = covariance_matrix(&out, Estimator::Sample).unwrap();
+///     assert!((cov[0][0] - 1.0).abs() < 1e-12 && (cov[1][1] - 1.0).abs() < 1e-12);
+///     assert!(cov[0][1].abs() < 1e-12);
+/// }
+/// ```
+pub fn whiten(columns: &[&[f64]], method: Whitening) -> Option<(Whitener, Vec<Vec<f64>>)> {
+    let covariance = covariance_matrix(columns, Estimator::Sample)?;
+    let eigen = symmetric_eigen(&covariance);
+    let largest = eigen[0].0;
+    if !eigen.iter().all(|(value, _)| *value > 1e-12 * largest) {
+        return None;
+    }
+    // Rows of the PCA transform: each eigenvector scaled by
+    // the reciprocal square root of its eigenvalue.
+    let pca: Vec<Vec<f64>> = eigen
+        .iter()
+        .map(|(value, vector)| vector.iter().map(|v| v / value.sqrt()).collect())
+        .collect();
+    let matrix = match method {
+        Whitening::Pca => pca,
+        Whitening::Zca => {
+            let k = pca.len();
+            (0..k)
+                .map(|i| {
+                    (0..k)
+                        .map(|j| (0..k).map(|c| eigen[c].1[i] * pca[c][j]).sum())
+                        .collect()
+                })
+                .collect()
+        }
+    };
+    let whitener = Whitener {
+        mean: column_means(columns),
+        matrix,
+    };
+    let transformed = whitener.apply(columns)?;
+    Some((whitener, transformed))
+}
+
+/// Mean of each of the (non-empty) columns.
+fn column_means(columns: &[&[f64]]) -> Vec<f64> {
+    columns
+        .iter()
+        .map(|c| c.iter().sum::<f64>() / c.len() as f64)
+        .collect()
+}
@@ -0,0 +1,394 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Rank-based hypothesis tests, which make no assumption
+//! about the shape of the underlying distributions.
+
+use crate::cmp_f64;
+use crate::special::{chi_square_sf, kolmogorov_sf, normal_cdf};
+use crate::{Ecdf, TestResult};
+
+/// Largest sample for which `RankMethod::Exact` is defined:
+/// the exact null distributions cost time polynomial in the
+/// sample sizes, which grows too quickly beyond it.
+pub const RANK_EXACT_MAX: usize = 50;
+
+/// How the p-value of a rank test is computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RankMethod {
+    /// Exact permutation distribution of the statistic,
+    /// defined only when there are no ties and for samples
+    /// of at most `RANK_EXACT_MAX` values.
+    Exact,
+    /// Normal approximation with tie and continuity
+    /// corrections.
+    Asymptotic,
+}
+
+/// Average ranks, starting at 1, of input values, with tied
+/// values sharing the mean of their ranks. Also returns the
+/// tie correction `Σ (t³ - t)` over groups of `t` tied
+/// values.
+pub(crate) fn ranks(nums: &[f64]) -> (Vec<f64>, f64) {
+    let mut order: Vec<usize> = (0..nums.len()).collect();
+    order.sort_by(|&i, &j| cmp_f64(&nums[i], &nums[j]));
+    let mut ranks = vec![0.0; nums.len()];
+    let mut ties = 0.0;
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && nums[order[end]] == nums[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        let t = (end - start) as f64;
+        ties += t * t * t - t;
+        start = end;
+    }
+    (ranks, ties)
+}
+
+/// Two-sided p-value of a statistic with the given
+/// null distribution, indexed by statistic value.
+fn exact_p_value(distribution: &[f64], statistic: usize) -> f64 {
+    let below = distribution[..=statistic].iter().sum::<f64>();
+    let above = distribution[statistic..].iter().sum::<f64>();
+    (2.0 * below.min(above)).min(1.0)
+}
+
+/// Two-sided p-value of a statistic with the given null
+/// mean and variance, by the normal approximation with a
+/// continuity correction.
+fn normal_p_value(statistic: f64, mean: f64, variance: f64) -> f64 {
+    let z = ((statistic - mean).abs() - 0.5).max(0.0) / variance.sqrt();
+    (2.0 * normal_cdf(-z)).min(1.0)
+}
+
+/// Null distribution of the Mann–Whitney U statistic for
+/// samples of sizes `m` and `n` without ties: entry `u` is
+/// the probability that `U = u`.
+fn mann_whitney_distribution(m: usize, n: usize) -> Vec<f64> {
+    // ways[k][s]: number of k-subsets of the ranks seen so
+    // far whose ranks sum (less k(k+1)/2) to s.
+    let max_u = m * n;
+    let mut ways = vec![vec![0.0; max_u + 1]; m + 1];
+    ways[0][0] = 1.0;
+    for item in 0..m + n {
+        for k in (1..=m.min(item + 1)).rev() {
+            // Adding rank item + 1 as the k-th smallest
+            // member shifts U by item + 1 - k.
+            let shift = item + 1 - k;
+            if shift > n {
+                continue;
+            }
+            for s in (shift..=max_u).rev() {
+                ways[k][s] += ways[k - 1][s - shift];
+            }
+        }
+    }
+    let total = ways[m].iter().sum::<f64>();
+    ways[m].iter().map(|w| w / total).collect()
+}
+
+/// Mann–Whitney U test (Wilcoxon rank-sum test) of whether
+/// values in `a` tend to be larger or smaller than those in
+/// `b`. The statistic is `U` for `a`: the number of pairs
+/// in which the value from `a` is larger, counting ties as
+/// one half. The test is undefined for an empty sample, and
+/// the exact method is undefined when there are ties or for
+/// a sample of more than `RANK_EXACT_MAX` values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, mann_whitney_u(&[], &[1.0], RankMethod::Asymptotic));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = mann_whitney_u(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], RankMethod::Exact).unwrap();
+/// assert_eq!(0.0, r.statistic);
+/// assert!((r.p_value - 0.1).abs() < 1e-12);
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = mann_whitney_u(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], RankMethod::Asymptotic).unwrap();
+/// assert!((r.p_value - 0.080856).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::*;
+/// let (a, b) = ([1.0, 2.0, 2.0, 7.0], [2.0, 3.0, 8.0]);
+/// assert_eq!(None, mann_whitney_u(&a, &b, RankMethod::Exact));
+/// let r = mann_whitney_u(&a, &b, RankMethod::Asymptotic).unwrap();
+/// assert_eq!(3.0, r.statistic);
+/// ```
+/// ```
+/// # use stats::*;
+/// let a: Vec<f64> = (0..300).map(|i| i as f64).collect();
+/// let b: Vec<f64> = (0..300).map(|i| i as f64 + 0.5).collect();
+/// assert_eq!(None, mann_whitney_u(&a, &b, RankMethod::Exact));
+/// assert!(mann_whitney_u(&a, &b, RankMethod::Asymptotic).is_some());
+/// ```
+pub fn mann_whitney_u(a: &[f64], b: &[f64], method: RankMethod) -> Option<TestResult> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let (m, n) = (a.len(), b.len());
+    let pooled: Vec<f64> = a.iter().chain(b).cloned().collect();
+    let (ranks, ties) = ranks(&pooled);
+    let rank_sum = ranks[..m].iter().sum::<f64>();
+    let statistic = rank_sum - (m * (m + 1)) as f64 / 2.0;
+    let p_value = match method {
+        RankMethod::Exact if ties > 0.0 || m.max(n) > RANK_EXACT_MAX => return None,
+        RankMethod::Exact => exact_p_value(&mann_whitney_distribution(m, n), statistic as usize),
+        RankMethod::Asymptotic => {
+            let (mf, nf, total) = (m as f64, n as f64, (m + n) as f64);
+            let variance = mf * nf / 12.0 * ((total + 1.0) - ties / (total * (total - 1.0)));
+            if variance <= 0.0 {
+                1.0
+            } else {
+                normal_p_value(statistic, mf * nf / 2.0, variance)
+            }
+        }
+    };
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value,
+    })
+}
+
+/// Null distribution of the Wilcoxon signed-rank statistic
+/// for `n` nonzero differences without ties: entry `w` is
+/// the probability that `W+ = w`.
+fn signed_rank_distribution(n: usize) -> Vec<f64> {
+    let max_w = n * (n + 1) / 2;
+    let mut ways = vec![0.0; max_w + 1];
+    ways[0] = 1.0;
+    for rank in 1..=n {
+        for w in (rank..=max_w).rev() {
+            ways[w] += ways[w - rank];
+        }
+    }
+    let total = 2.0f64.powi(n as i32);
+    ways.iter().map(|w| w / total).collect()
+}
+
+/// Wilcoxon signed-rank test of whether the differences
+/// `after[i] - before[i]` are symmetric about zero. Zero
+/// differences are discarded and tied absolute differences
+/// share their average rank. The statistic is `W+`, the sum
+/// of the ranks of the positive differences. The test is
+/// undefined for unequal-length inputs or when every
+/// difference is zero, and the exact method is undefined
+/// when there are ties or more than `RANK_EXACT_MAX`
+/// nonzero differences.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, wilcoxon_signed_rank(&[1.0], &[1.0], RankMethod::Asymptotic));
+/// ```
+/// ```
+/// # use stats::*;
+/// let before = [1.0, 1.0, 1.0, 1.0, 1.0, 3.0];
+/// let after = [2.0, 3.0, 4.0, 5.0, 6.0, 3.0];
+/// let r = wilcoxon_signed_rank(&before, &after, RankMethod::Exact).unwrap();
+/// assert_eq!(15.0, r.statistic);
+/// assert!((r.p_value - 0.0625).abs() < 1e-12);
+/// let r = wilcoxon_signed_rank(&before, &after, RankMethod::Asymptotic).unwrap();
+/// assert!((r.p_value - 0.059058).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::*;
+/// let before = vec![0.0; 2000];
+/// let after: Vec<f64> = (1..=2000).map(|i| i as f64).collect();
+/// assert_eq!(None, wilcoxon_signed_rank(&before, &after, RankMethod::Exact));
+/// let r = wilcoxon_signed_rank(&before, &after, RankMethod::Asymptotic).unwrap();
+/// assert!(r.p_value < 1e-12);
+/// ```
+pub fn wilcoxon_signed_rank(
+    before: &[f64],
+    after: &[f64],
+    method: RankMethod,
+) -> Option<TestResult> {
+    if before.len() != after.len() {
+        return None;
+    }
+    let differences: Vec<f64> = before
+        .iter()
+        .zip(after)
+        .map(|(b, a)| a - b)
+        .filter(|&d| d != 0.0)
+        .collect();
+    if differences.is_empty() {
+        return None;
+    }
+    let magnitudes: Vec<f64> = differences.iter().map(|d| d.abs()).collect();
+    let (ranks, ties) = ranks(&magnitudes);
+    let statistic = ranks
+        .iter()
+        .zip(&differences)
+        .filter(|(_, &d)| d > 0.0)
+        .map(|(r, _)| r)
+        .sum::<f64>();
+    let n = differences.len();
+    let p_value = match method {
+        RankMethod::Exact if ties > 0.0 || n > RANK_EXACT_MAX => return None,
+        RankMethod::Exact => exact_p_value(&signed_rank_distribution(n), statistic as usize),
+        RankMethod::Asymptotic => {
+            let nf = n as f64;
+            let variance = nf * (nf + 1.0) * (2.0 * nf + 1.0) / 24.0 - ties / 48.0;
+            if variance <= 0.0 {
+                1.0
+            } else {
+                normal_p_value(statistic, nf * (nf + 1.0) / 4.0, variance)
+            }
+        }
+    };
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value,
+    })
+}
+
+/// Kruskal–Wallis H test of whether several groups come
+/// from the same distribution, with ties corrected for. The
+/// statistic is compared against the chi-square
+/// distribution with one fewer degrees of freedom than
+/// groups; the p-value is upper-tail. The test is undefined
+/// for fewer than two groups, for an empty group, or when
+/// every value is the same.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, kruskal_wallis(&[&[1.0, 1.0], &[1.0]]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = kruskal_wallis(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]]).unwrap();
+/// assert!((r.statistic - 7.2).abs() < 1e-12);
+/// assert_eq!(Some(2.0), r.df);
+/// assert!((r.p_value - (-3.6f64).exp()).abs() < 1e-12);
+/// ```
+pub fn kruskal_wallis(groups: &[&[f64]]) -> Option<TestResult> {
+    if groups.len() < 2 || groups.iter().any(|g| g.is_empty()) {
+        return None;
+    }
+    let pooled: Vec<f64> = groups.iter().flat_map(|g| g.iter()).cloned().collect();
+    let (ranks, ties) = ranks(&pooled);
+    let n = pooled.len() as f64;
+    let correction = 1.0 - ties / (n * n * n - n);
+    if correction <= 0.0 {
+        return None;
+    }
+    let mut start = 0;
+    let mut spread = 0.0;
+    for g in groups {
+        let rank_sum = ranks[start..start + g.len()].iter().sum::<f64>();
+        spread += rank_sum * rank_sum / g.len() as f64;
+        start += g.len();
+    }
+    let statistic = (12.0 / (n * (n + 1.0)) * spread - 3.0 * (n + 1.0)) / correction;
+    let df = (groups.len() - 1) as f64;
+    Some(TestResult {
+        statistic,
+        df: Some(df),
+        p_value: chi_square_sf(statistic, df),
+    })
+}
+
+/// Asymptotic p-value of a Kolmogorov–Smirnov statistic `d`
+/// for effective sample size `n`, with Stephens' small-sample
+/// adjustment.
+fn ks_p_value(d: f64, n: f64) -> f64 {
+    let root = n.sqrt();
+    kolmogorov_sf((root + 0.12 + 0.11 / root) * d)
+}
+
+/// One-sample Kolmogorov–Smirnov test of whether input
+/// values are drawn from the continuous distribution with
+/// cumulative distribution function `cdf`. The statistic is
+/// the largest distance between the empirical and
+/// hypothesized distribution functions. The test is
+/// undefined for an empty list.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, ks_test(&[], |x| x));
+/// ```
+/// ```
+/// # use stats::*;
+/// let uniform = |x: f64| x.clamp(0.0, 1.0);
+/// let r = ks_test(&[0.1, 0.4, 0.7], uniform).unwrap();
+/// assert!((r.statistic - 0.3).abs() < 1e-12);
+/// assert!((r.p_value - 0.895945).abs() < 1e-6);
+/// ```
+pub fn ks_test<F: Fn(f64) -> f64>(nums: &[f64], cdf: F) -> Option<TestResult> {
+    let ecdf = Ecdf::new(nums)?;
+    let n = ecdf.len() as f64;
+    let statistic = ecdf
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x);
+            (((i + 1) as f64 / n) - f).max(f - i as f64 / n)
+        })
+        .fold(0.0, f64::max);
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value: ks_p_value(statistic, n),
+    })
+}
+
+/// Two-sample Kolmogorov–Smirnov test of whether `a` and
+/// `b` are drawn from the same continuous distribution. The
+/// statistic is the largest distance between their
+/// empirical distribution functions. The test is undefined
+/// for an empty sample.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, ks_test_two(&[1.0], &[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = ks_test_two(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]).unwrap();
+/// assert_eq!(1.0, r.statistic);
+/// assert!((r.p_value - 0.032622).abs() < 1e-6);
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = ks_test_two(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]).unwrap();
+/// assert_eq!((0.0, 1.0), (r.statistic, r.p_value));
+/// ```
+pub fn ks_test_two(a: &[f64], b: &[f64]) -> Option<TestResult> {
+    let (ea, eb) = (Ecdf::new(a)?, Ecdf::new(b)?);
+    let statistic = ea
+        .values()
+        .iter()
+        .chain(eb.values())
+        .map(|&x| (ea.eval(x) - eb.eval(x)).abs())
+        .fold(0.0, f64::max);
+    let (na, nb) = (ea.len() as f64, eb.len() as f64);
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value: ks_p_value(statistic, na * nb / (na + nb)),
+    })
+}
@@ -0,0 +1,213 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Tests of whether a sample is drawn from a normal
+//! distribution. Small p-values are evidence against
+//! normality.
+
+use crate::special::{normal_cdf, normal_quantile};
+use crate::{mean, sample_variance, sorted, TestResult};
+use std::f64::consts::PI;
+
+/// Largest sample `shapiro_wilk` accepts: Royston's
+/// approximation is not calibrated beyond it.
+pub const SHAPIRO_WILK_MAX: usize = 5000;
+
+/// Largest adjusted Anderson–Darling statistic at which the
+/// p-value approximation is evaluated. Its quadratic term
+/// would make the p-value grow again for statistics above
+/// about 150, so larger ones get the (negligible) p-value
+/// at this bound.
+pub const ANDERSON_DARLING_MAX_ADJUSTED: f64 = 10.0;
+
+/// Value of the polynomial with coefficients `coeffs`
+/// (constant term first) at `x`.
+fn poly(coeffs: &[f64], x: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// Shapiro–Wilk W coefficients for a sample of size `n >=
+/// 4`, by Royston's approximation (Applied Statistics
+/// algorithm AS R94).
+fn shapiro_wilk_coefficients(n: usize) -> Vec<f64> {
+    const C1: [f64; 6] = [0.0, 0.221_157, -0.147_981, -2.071_19, 4.434_685, -2.706_056];
+    const C2: [f64; 6] = [
+        0.0, 0.042_981, -0.293_762, -1.752_461, 5.682_633, -3.582_633,
+    ];
+    let nf = n as f64;
+    let m: Vec<f64> = (1..=n)
+        .map(|i| normal_quantile((i as f64 - 0.375) / (nf + 0.25)))
+        .collect();
+    let ss = m.iter().map(|v| v * v).sum::<f64>();
+    let u = 1.0 / nf.sqrt();
+    let an = m[n - 1] / ss.sqrt() + poly(&C1, u);
+    let mut a: Vec<f64>;
+    if n > 5 {
+        let an1 = m[n - 2] / ss.sqrt() + poly(&C2, u);
+        let phi = (ss - 2.0 * m[n - 1].powi(2) - 2.0 * m[n - 2].powi(2))
+            / (1.0 - 2.0 * an * an - 2.0 * an1 * an1);
+        a = m.iter().map(|v| v / phi.sqrt()).collect();
+        a[n - 2] = an1;
+        a[1] = -an1;
+    } else {
+        let phi = (ss - 2.0 * m[n - 1].powi(2)) / (1.0 - 2.0 * an * an);
+        a = m.iter().map(|v| v / phi.sqrt()).collect();
+    }
+    a[n - 1] = an;
+    a[0] = -an;
+    a
+}
+
+/// Shapiro–Wilk test of normality. The statistic W is at
+/// most 1, with smaller values indicating departure from
+/// normality; the p-value is from Royston's approximation
+/// (exact for three values). The test is undefined for
+/// fewer than three or more than `SHAPIRO_WILK_MAX` values,
+/// or for constant input.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, shapiro_wilk(&[1.0, 2.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = shapiro_wilk(&[1.0, 2.0, 3.0]).unwrap();
+/// assert!((r.statistic - 1.0).abs() < 1e-12);
+/// assert!((r.p_value - 1.0).abs() < 1e-9);
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = shapiro_wilk(&[1.0, 2.0, 4.0]).unwrap();
+/// assert!((r.statistic - 27.0 / 28.0).abs() < 1e-12);
+/// assert!((r.p_value - 0.6369).abs() < 1e-4);
+/// ```
+/// ```
+/// # use stats::*;
+/// let normalish = [-1.6, -1.0, -0.7, -0.4, -0.2, 0.0, 0.2, 0.4, 0.7, 1.0, 1.6, 0.1];
+/// assert!(shapiro_wilk(&normalish).unwrap().p_value > 0.5);
+/// let skewed: Vec<f64> = (0..20).map(|i| 2.0f64.powi(i)).collect();
+/// assert!(shapiro_wilk(&skewed).unwrap().p_value < 1e-4);
+/// ```
+pub fn shapiro_wilk(nums: &[f64]) -> Option<TestResult> {
+    let n = nums.len();
+    if !(3..=SHAPIRO_WILK_MAX).contains(&n) {
+        return None;
+    }
+    let ss = sample_variance(nums)? * (n - 1) as f64;
+    if ss == 0.0 {
+        return None;
+    }
+    let x = sorted(nums);
+    let a = if n == 3 {
+        vec![-0.5f64.sqrt(), 0.0, 0.5f64.sqrt()]
+    } else {
+        shapiro_wilk_coefficients(n)
+    };
+    let w = (a.iter().zip(&x).map(|(a, x)| a * x).sum::<f64>().powi(2) / ss).min(1.0);
+
+    let p_value = if n == 3 {
+        // Exact distribution for three values.
+        let p = 6.0 / PI * (w.sqrt().asin() - 0.75f64.sqrt().asin());
+        p.clamp(0.0, 1.0)
+    } else {
+        let nf = n as f64;
+        let y = (1.0 - w).ln();
+        let (y, mu, sigma) = if n <= 11 {
+            let gamma = poly(&[-2.273, 0.459], nf);
+            if y >= gamma {
+                return Some(TestResult {
+                    statistic: w,
+                    df: None,
+                    p_value: 0.0,
+                });
+            }
+            let mu = poly(&[0.544, -0.399_78, 0.025_054, -6.714e-4], nf);
+            let sigma = poly(&[1.382_2, -0.778_57, 0.062_767, -0.002_032_2], nf).exp();
+            (-(gamma - y).ln(), mu, sigma)
+        } else {
+            let ln_n = nf.ln();
+            let mu = poly(&[-1.586_1, -0.310_82, -0.083_751, 0.003_891_5], ln_n);
+            let sigma = poly(&[-0.480_3, -0.082_676, 0.003_030_2], ln_n).exp();
+            (y, mu, sigma)
+        };
+        normal_cdf(-(y - mu) / sigma)
+    };
+    Some(TestResult {
+        statistic: w,
+        df: None,
+        p_value,
+    })
+}
+
+/// Anderson–Darling test of normality, with the mean and
+/// variance estimated from the sample. The statistic is A²;
+/// the p-value applies Stephens' small-sample adjustment
+/// `A² (1 + 0.75 / n + 2.25 / n²)` and the approximations of
+/// D'Agostino and Stephens (1986), evaluated at no more
+/// than `ANDERSON_DARLING_MAX_ADJUSTED`. The test is
+/// undefined for fewer than three values or for constant
+/// input.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, anderson_darling(&[1.0, 1.0, 1.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let normalish = [-1.6, -1.0, -0.7, -0.4, -0.2, 0.0, 0.2, 0.4, 0.7, 1.0, 1.6, 0.1];
+/// let r = anderson_darling(&normalish).unwrap();
+/// assert!(r.statistic < 0.2 && r.p_value > 0.5);
+/// let skewed: Vec<f64> = (0..20).map(|i| 2.0f64.powi(i)).collect();
+/// assert!(anderson_darling(&skewed).unwrap().p_value < 1e-4);
+/// ```
+/// ```
+/// # use stats::*;
+/// // Far from normal: the statistic is in the hundreds.
+/// let growing: Vec<f64> = (0..2000).map(|i| 1.01f64.powi(i)).collect();
+/// let r = anderson_darling(&growing).unwrap();
+/// assert!(r.statistic > 100.0);
+/// assert!(r.p_value < 1e-20);
+/// ```
+pub fn anderson_darling(nums: &[f64]) -> Option<TestResult> {
+    let n = nums.len();
+    if n < 3 {
+        return None;
+    }
+    let (m, s) = (mean(nums)?, sample_variance(nums)?.sqrt());
+    if s == 0.0 {
+        return None;
+    }
+    let z: Vec<f64> = sorted(nums).iter().map(|x| (x - m) / s).collect();
+    let nf = n as f64;
+    let sum = (0..n)
+        .map(|i| {
+            // ln F(z_i) + ln(1 - F(z_(n+1-i))), using the
+            // symmetry of the normal for the upper tail.
+            let weight = (2 * i + 1) as f64;
+            weight * (normal_cdf(z[i]).ln() + normal_cdf(-z[n - 1 - i]).ln())
+        })
+        .sum::<f64>();
+    let statistic = -nf - sum / nf;
+    let adjusted = statistic * (1.0 + 0.75 / nf + 2.25 / (nf * nf));
+    let p_value = if adjusted >= 0.6 {
+        let a = adjusted.min(ANDERSON_DARLING_MAX_ADJUSTED);
+        (1.2937 - 5.709 * a + 0.0186 * a * a).exp()
+    } else if adjusted >= 0.34 {
+        (0.9177 - 4.279 * adjusted - 1.38 * adjusted * adjusted).exp()
+    } else if adjusted >= 0.2 {
+        1.0 - (-8.318 + 42.796 * adjusted - 59.938 * adjusted * adjusted).exp()
+    } else {
+        1.0 - (-13.436 + 101.14 * adjusted - 223.73 * adjusted * adjusted).exp()
+    };
+    Some(TestResult {
+        statistic,
+        df: None,
+        p_value: p_value.clamp(0.0, 1.0),
+    })
+}
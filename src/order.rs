@@ -0,0 +1,95 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Order statistics by selection, in linear expected time
+//! rather than by sorting all of the input. Values are
+//! ordered as elsewhere in the crate, with NaN largest.
+
+use crate::cmp_f64;
+
+/// The `k`-th smallest input value, counting from 1 for the
+/// minimum. It is undefined for `k` of 0 or greater than the
+/// number of values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, kth_smallest(&[1.0, 2.0], 0));
+/// assert_eq!(None, kth_smallest(&[1.0, 2.0], 3));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [5.0, 1.0, 4.0, 2.0, 3.0];
+/// assert_eq!(Some(1.0), kth_smallest(&nums, 1));
+/// assert_eq!(Some(2.0), kth_smallest(&nums, 2));
+/// assert_eq!(Some(5.0), kth_smallest(&nums, 5));
+/// ```
+/// ```
+/// # use stats::*;
+/// // NaN is largest, even with its sign bit set.
+/// let nums = [1.0, -f64::NAN, 2.0];
+/// assert_eq!(Some(1.0), kth_smallest(&nums, 1));
+/// assert!(kth_largest(&nums, 1).unwrap().is_nan());
+/// assert_eq!(2.0, top_k(&nums, 2)[1]);
+/// ```
+pub fn kth_smallest(nums: &[f64], k: usize) -> Option<f64> {
+    if k == 0 || k > nums.len() {
+        return None;
+    }
+    let mut nums = nums.to_owned();
+    let (_, kth, _) = nums.select_nth_unstable_by(k - 1, cmp_f64);
+    Some(*kth)
+}
+
+/// The `k`-th largest input value, counting from 1 for the
+/// maximum. It is undefined for `k` of 0 or greater than the
+/// number of values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let nums = [5.0, 1.0, 4.0, 2.0, 3.0];
+/// assert_eq!(Some(5.0), kth_largest(&nums, 1));
+/// assert_eq!(Some(4.0), kth_largest(&nums, 2));
+/// assert_eq!(None, kth_largest(&nums, 6));
+/// ```
+pub fn kth_largest(nums: &[f64], k: usize) -> Option<f64> {
+    if k == 0 || k > nums.len() {
+        return None;
+    }
+    kth_smallest(nums, nums.len() + 1 - k)
+}
+
+/// The `k` largest input values, largest first; all of the
+/// values if there are no more than `k`. Only those `k`
+/// values are sorted.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let latencies = [12.0, 250.0, 8.0, 31.0, 900.0, 15.0];
+/// assert_eq!(vec![900.0, 250.0, 31.0], top_k(&latencies, 3));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(vec![2.0, 1.0], top_k(&[1.0, 2.0], 5));
+/// assert!(top_k(&[1.0, 2.0], 0).is_empty());
+/// ```
+pub fn top_k(nums: &[f64], k: usize) -> Vec<f64> {
+    let mut nums = nums.to_owned();
+    if k == 0 {
+        return Vec::new();
+    }
+    if k < nums.len() {
+        let split = nums.len() - k;
+        nums.select_nth_unstable_by(split, cmp_f64);
+        nums.drain(..split);
+    }
+    nums.sort_by(|a, b| cmp_f64(b, a));
+    nums
+}
@@ -0,0 +1,201 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Outlier detection.
+
+use crate::plot::iqr_fences;
+use crate::special::student_t_quantile;
+use crate::{mad, mean, median, sample_variance, sorted, stddev, MAD_SCALE};
+
+/// How z-scores are computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scoring {
+    /// `(x - mean) / stddev`.
+    Standard,
+    /// `(x - median) / (1.4826 MAD)`, which is not itself
+    /// distorted by the outliers it is looking for.
+    Robust,
+}
+
+/// Indexes of input values whose z-score exceeds
+/// `threshold` in absolute value, in ascending order.
+/// Constant input has no outliers.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(outliers_zscore(&[], 3.0, Scoring::Standard).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [1.0, 2.0, 1.5, 2.5, 1.0, 30.0];
+/// assert_eq!(vec![5], outliers_zscore(&nums, 2.0, Scoring::Standard));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [1.0, 2.0, 1.5, 2.5, 1.0, 30.0, 40.0];
+/// assert!(outliers_zscore(&nums, 3.0, Scoring::Standard).is_empty());
+/// assert_eq!(vec![5, 6], outliers_zscore(&nums, 3.0, Scoring::Robust));
+/// ```
+pub fn outliers_zscore(nums: &[f64], threshold: f64, scoring: Scoring) -> Vec<usize> {
+    let center_scale = match scoring {
+        Scoring::Standard => mean(nums).zip(stddev(nums)),
+        Scoring::Robust => median(nums).zip(mad(nums).map(|m| MAD_SCALE * m)),
+    };
+    match center_scale {
+        Some((center, scale)) if scale > 0.0 => nums
+            .iter()
+            .enumerate()
+            .filter(|(_, &v)| ((v - center) / scale).abs() > threshold)
+            .map(|(i, _)| i)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Indexes of input values outside the fences `[Q1 - k IQR,
+/// Q3 + k IQR]`, in ascending order. `WHISKER_IQR` gives the
+/// conventional `k = 1.5`, matching the whiskers of
+/// `boxplot_stats`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(outliers_iqr(&[], WHISKER_IQR).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [20.0, 1.0, 2.0, 3.0, 4.0, 5.0, -10.0];
+/// assert_eq!(vec![0, 6], outliers_iqr(&nums, WHISKER_IQR));
+/// assert_eq!(vec![0], outliers_iqr(&nums, 4.0));
+/// ```
+pub fn outliers_iqr(nums: &[f64], k: f64) -> Vec<usize> {
+    if nums.is_empty() {
+        return Vec::new();
+    }
+    let (lo, hi) = iqr_fences(&sorted(nums), k);
+    nums.iter()
+        .enumerate()
+        .filter(|(_, &v)| v < lo || v > hi)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Outcome of Grubbs' test for a single outlier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrubbsResult {
+    /// Index of the value furthest from the mean.
+    pub index: usize,
+    /// Grubbs statistic: that value's distance from the
+    /// mean in sample standard deviations.
+    pub statistic: f64,
+    /// Critical value of the statistic at the requested
+    /// significance level.
+    pub critical: f64,
+    /// Whether the statistic exceeds the critical value.
+    pub outlier: bool,
+}
+
+/// Two-sided Grubbs' test of whether the value furthest
+/// from the mean is an outlier at significance level
+/// `alpha`, assuming the rest of the data is normal. The
+/// test is undefined for fewer than three values, for
+/// constant input, or for `alpha` outside `(0, 1)`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, grubbs_test(&[1.0, 2.0], 0.05));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [2.1, 1.9, 2.0, 2.2, 1.8, 2.05, 5.0];
+/// let g = grubbs_test(&nums, 0.05).unwrap();
+/// assert_eq!(6, g.index);
+/// assert!(g.outlier);
+/// assert!((g.critical - 2.0199).abs() < 1e-4);
+/// ```
+/// ```
+/// # use stats::*;
+/// let g = grubbs_test(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.05).unwrap();
+/// assert!(!g.outlier);
+/// ```
+pub fn grubbs_test(nums: &[f64], alpha: f64) -> Option<GrubbsResult> {
+    if nums.len() < 3 || !(alpha > 0.0 && alpha < 1.0) {
+        return None;
+    }
+    let m = mean(nums)?;
+    let s = sample_variance(nums)?.sqrt();
+    if s == 0.0 {
+        return None;
+    }
+    let (index, distance) =
+        nums.iter()
+            .map(|v| (v - m).abs())
+            .enumerate()
+            .fold(
+                (0, 0.0),
+                |best, (i, d)| if d > best.1 { (i, d) } else { best },
+            );
+    let statistic = distance / s;
+    let n = nums.len() as f64;
+    let t = student_t_quantile(1.0 - alpha / (2.0 * n), n - 2.0);
+    let critical = (n - 1.0) / n.sqrt() * (t * t / (n - 2.0 + t * t)).sqrt();
+    Some(GrubbsResult {
+        index,
+        statistic,
+        critical,
+        outlier: statistic > critical,
+    })
+}
+
+/// Hampel filter for despiking a series. Each value is
+/// compared with the median of the window of up to `window`
+/// values on either side of it; values more than `n_sigmas`
+/// scaled MADs (`1.4826 MAD`) from that median are replaced
+/// by it. Returns the cleaned series and the indexes of the
+/// replaced values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let (clean, replaced) = hampel_filter(&[], 3, 3.0);
+/// assert!(clean.is_empty() && replaced.is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [1.0, 1.2, 0.9, 1.1, 9.0, 1.05, 0.95, 1.0];
+/// let (clean, replaced) = hampel_filter(&nums, 2, 3.0);
+/// assert_eq!(vec![4], replaced);
+/// assert_eq!(1.05, clean[4]);
+/// assert_eq!(nums[..4], clean[..4]);
+/// ```
+/// ```
+/// # use stats::*;
+/// // A window wider than the series covers all of it.
+/// let (clean, replaced) = hampel_filter(&[1.0, 1.1, 0.9, 50.0, 1.0], usize::MAX, 3.0);
+/// assert_eq!(vec![3], replaced);
+/// assert_eq!(1.0, clean[3]);
+/// ```
+pub fn hampel_filter(nums: &[f64], window: usize, n_sigmas: f64) -> (Vec<f64>, Vec<usize>) {
+    let mut clean = nums.to_owned();
+    let mut replaced = Vec::new();
+    for (i, &v) in nums.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = i.saturating_add(window).saturating_add(1).min(nums.len());
+        let neighborhood = &nums[lo..hi];
+        let m = median(neighborhood).unwrap();
+        let scale = MAD_SCALE * mad(neighborhood).unwrap();
+        if (v - m).abs() > n_sigmas * scale {
+            clean[i] = m;
+            replaced.push(i);
+        }
+    }
+    (clean, replaced)
+}
@@ -0,0 +1,136 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Derived data for statistical plots.
+
+use crate::special::normal_quantile;
+use crate::{sorted, sorted_quantile};
+
+/// Whisker reach of a box plot, in multiples of the
+/// interquartile range.
+pub const WHISKER_IQR: f64 = 1.5;
+
+/// Statistics needed to draw a box plot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxplotStats {
+    /// First quartile.
+    pub q1: f64,
+    /// Median.
+    pub median: f64,
+    /// Third quartile.
+    pub q3: f64,
+    /// Smallest value no further than `WHISKER_IQR`
+    /// interquartile ranges below `q1`.
+    pub whisker_low: f64,
+    /// Largest value no further than `WHISKER_IQR`
+    /// interquartile ranges above `q3`.
+    pub whisker_high: f64,
+    /// Values beyond the whiskers, in ascending order.
+    pub outliers: Vec<f64>,
+}
+
+/// Fences `[q1 - k IQR, q3 + k IQR]` of an already-sorted,
+/// non-empty slice.
+pub(crate) fn iqr_fences(nums: &[f64], k: f64) -> (f64, f64) {
+    let q1 = sorted_quantile(nums, 0.25);
+    let q3 = sorted_quantile(nums, 0.75);
+    let iqr = q3 - q1;
+    (q1 - k * iqr, q3 + k * iqr)
+}
+
+/// Box plot statistics of input values, with quartiles
+/// interpolated as by `quantile`. The box plot is
+/// undefined for an empty list, or when no value lies
+/// within the whisker fences, as when every value is NaN.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, boxplot_stats(&[]));
+/// assert_eq!(None, boxplot_stats(&[f64::NAN, f64::NAN]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let b = boxplot_stats(&[3.0, 1.0, 2.0, 4.0, 5.0]).unwrap();
+/// assert_eq!((2.0, 3.0, 4.0), (b.q1, b.median, b.q3));
+/// assert_eq!((1.0, 5.0), (b.whisker_low, b.whisker_high));
+/// assert!(b.outliers.is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let b = boxplot_stats(&[1.0, 2.0, 3.0, 4.0, 5.0, 20.0, -10.0]).unwrap();
+/// assert_eq!((1.0, 5.0), (b.whisker_low, b.whisker_high));
+/// assert_eq!(vec![-10.0, 20.0], b.outliers);
+/// ```
+pub fn boxplot_stats(nums: &[f64]) -> Option<BoxplotStats> {
+    if nums.is_empty() {
+        return None;
+    }
+    let nums = sorted(nums);
+    let (lo, hi) = iqr_fences(&nums, WHISKER_IQR);
+    let (inside, outliers): (Vec<f64>, Vec<f64>) = nums.iter().partition(|&&v| lo <= v && v <= hi);
+    if inside.is_empty() {
+        return None;
+    }
+    Some(BoxplotStats {
+        q1: sorted_quantile(&nums, 0.25),
+        median: sorted_quantile(&nums, 0.5),
+        q3: sorted_quantile(&nums, 0.75),
+        whisker_low: inside[0],
+        whisker_high: inside[inside.len() - 1],
+        outliers,
+    })
+}
+
+/// Probability plotting positions `(i - a) / (n + 1 - 2a)`
+/// for `i` in `1..=n`, with `a = 3/8` for `n <= 10` and `a =
+/// 1/2` otherwise, as used by R's `ppoints`.
+fn plotting_positions(n: usize) -> impl Iterator<Item = f64> {
+    let a = if n <= 10 { 0.375 } else { 0.5 };
+    (1..=n).map(move |i| (i as f64 - a) / (n as f64 + 1.0 - 2.0 * a))
+}
+
+/// Quantile-quantile plot points of input values against a
+/// theoretical distribution given by its quantile function:
+/// pairs `(theoretical, sample)` in ascending order. The
+/// points of an empty list are empty.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let uniform = |p: f64| p;
+/// let points = qq_points(&[0.9, 0.1, 0.5], uniform);
+/// assert_eq!(0.5, points[1].0);
+/// assert_eq!(vec![0.1, 0.5, 0.9], points.iter().map(|p| p.1).collect::<Vec<_>>());
+/// ```
+pub fn qq_points<F: Fn(f64) -> f64>(nums: &[f64], quantile: F) -> Vec<(f64, f64)> {
+    plotting_positions(nums.len())
+        .map(quantile)
+        .zip(sorted(nums))
+        .collect()
+}
+
+/// Quantile-quantile plot points of input values against
+/// the standard normal distribution, for a visual check of
+/// normality.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(qq_points_normal(&[]).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let points = qq_points_normal(&[2.0, -1.0, 0.0]);
+/// assert_eq!((0.0, 0.0), points[1]);
+/// assert!((points[0].0 + 0.8694238).abs() < 1e-6);
+/// assert!((points[0].0 + points[2].0).abs() < 1e-12);
+/// ```
+pub fn qq_points_normal(nums: &[f64]) -> Vec<(f64, f64)> {
+    qq_points(nums, normal_quantile)
+}
@@ -0,0 +1,107 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistical power and sample-size planning for t-tests.
+
+use crate::special::{noncentral_t_cdf, student_t_quantile};
+
+/// Experimental design of a t-test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Design {
+    /// One sample compared against a reference value, or
+    /// paired differences.
+    OneSample,
+    /// Two independent samples of equal size, with pooled
+    /// variance.
+    TwoSample,
+}
+
+/// Power of a two-sided t-test at significance level
+/// `alpha` to detect a standardized effect of size
+/// `effect_size` (Cohen's d), with `n` observations per
+/// sample. Computed exactly from the noncentral t
+/// distribution, counting rejections in both tails. Power
+/// is undefined for fewer than two observations per sample
+/// or for `alpha` outside `(0, 1)`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, power_t_test(0.5, 1, 0.05, Design::OneSample));
+/// ```
+/// ```
+/// # use stats::*;
+/// let power = power_t_test(0.5, 64, 0.05, Design::TwoSample).unwrap();
+/// assert!((power - 0.80146).abs() < 1e-5);
+/// ```
+/// ```
+/// # use stats::*;
+/// let power = power_t_test(0.5, 20, 0.05, Design::OneSample).unwrap();
+/// assert!((power - 0.56451).abs() < 1e-5);
+/// let power = power_t_test(0.0, 20, 0.05, Design::OneSample).unwrap();
+/// assert!((power - 0.05).abs() < 1e-9);
+/// ```
+pub fn power_t_test(effect_size: f64, n: usize, alpha: f64, design: Design) -> Option<f64> {
+    if n < 2 || !(alpha > 0.0 && alpha < 1.0) {
+        return None;
+    }
+    let nf = n as f64;
+    let (df, delta) = match design {
+        Design::OneSample => (nf - 1.0, effect_size * nf.sqrt()),
+        Design::TwoSample => (2.0 * nf - 2.0, effect_size * (nf / 2.0).sqrt()),
+    };
+    let critical = student_t_quantile(1.0 - alpha / 2.0, df);
+    let upper = 1.0 - noncentral_t_cdf(critical, df, delta);
+    let lower = noncentral_t_cdf(-critical, df, delta);
+    Some(upper + lower)
+}
+
+/// Smallest number of observations per sample for which a
+/// two-sided t-test at level `alpha` reaches `power` against
+/// an effect of size `effect_size`. See `power_t_test`. The
+/// sample size is undefined for a zero effect, for `power`
+/// not strictly between `alpha` and 1, or for `alpha`
+/// outside `(0, 1)`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, sample_size_t_test(0.0, 0.8, 0.05, Design::TwoSample));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(64), sample_size_t_test(0.5, 0.8, 0.05, Design::TwoSample));
+/// assert_eq!(Some(34), sample_size_t_test(0.5, 0.8, 0.05, Design::OneSample));
+/// assert_eq!(Some(34), sample_size_t_test(-0.5, 0.8, 0.05, Design::OneSample));
+/// ```
+pub fn sample_size_t_test(
+    effect_size: f64,
+    power: f64,
+    alpha: f64,
+    design: Design,
+) -> Option<usize> {
+    if effect_size == 0.0 || !effect_size.is_finite() || !(power > alpha && power < 1.0) {
+        return None;
+    }
+    let reaches = |n: usize| power_t_test(effect_size, n, alpha, design).map(|p| p >= power);
+    let mut hi = 2;
+    while !reaches(hi)? {
+        hi *= 2;
+    }
+    let mut lo = hi / 2;
+    // Invariant: lo does not reach the power (or is below
+    // the minimum size), hi does.
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if reaches(mid)? {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(hi)
+}
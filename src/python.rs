@@ -0,0 +1,222 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Python bindings, built with PyO3. Functions take
+//! one-dimensional NumPy arrays of floats and return a
+//! float, or `None` where the statistic is undefined.
+//! Build the extension module with `maturin`, enabling the
+//! `python` feature:
+//!
+//! ```python
+//! import numpy as np
+//! import stats
+//! x = np.array([1.0, 2.0, 4.0])
+//! stats.median(x)                # 2.0
+//! s = stats.Summary()
+//! s.push_all(x)
+//! s.mean()                       # 2.3333333333333335
+//! stats.shapiro_wilk(x).p_value  # 0.6369...
+//! ```
+
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+use std::borrow::Cow;
+
+/// Values of an array, copied only if it is not
+/// contiguous.
+fn values<'a>(array: &'a PyReadonlyArray1<f64>) -> Cow<'a, [f64]> {
+    match array.as_slice() {
+        Ok(slice) => Cow::Borrowed(slice),
+        Err(_) => Cow::Owned(array.as_array().to_vec()),
+    }
+}
+
+/// Arithmetic mean, as for `stats::mean`.
+#[pyfunction]
+fn mean(nums: PyReadonlyArray1<f64>) -> Option<f64> {
+    crate::mean(&values(&nums))
+}
+
+/// Population standard deviation, as for `stats::stddev`.
+#[pyfunction]
+fn stddev(nums: PyReadonlyArray1<f64>) -> Option<f64> {
+    crate::stddev(&values(&nums))
+}
+
+/// Median, as for `stats::median`.
+#[pyfunction]
+fn median(nums: PyReadonlyArray1<f64>) -> Option<f64> {
+    crate::median(&values(&nums))
+}
+
+/// Median absolute deviation, as for `stats::mad`.
+#[pyfunction]
+fn mad(nums: PyReadonlyArray1<f64>) -> Option<f64> {
+    crate::mad(&values(&nums))
+}
+
+/// Quantile `q`, as for `stats::quantile`.
+#[pyfunction]
+fn quantile(nums: PyReadonlyArray1<f64>, q: f64) -> Option<f64> {
+    crate::quantile(&values(&nums), q)
+}
+
+/// Running summary of a stream of values, as for
+/// `stats::Summary`.
+#[pyclass(skip_from_py_object)]
+#[derive(Debug, Clone, Default)]
+struct Summary(crate::Summary);
+
+#[pymethods]
+impl Summary {
+    /// Summary of no values.
+    #[new]
+    fn new() -> Summary {
+        Summary(crate::Summary::new())
+    }
+
+    /// Add a value to the summary.
+    fn push(&mut self, x: f64) {
+        self.0.push(x);
+    }
+
+    /// Add each value of an array to the summary.
+    fn push_all(&mut self, nums: PyReadonlyArray1<f64>) {
+        values(&nums).iter().for_each(|&x| self.0.push(x));
+    }
+
+    /// Fold in the summary of other values. `other` may be
+    /// this summary, which then counts its values twice.
+    fn merge(slf: &Bound<'_, Self>, other: &Bound<'_, Self>) {
+        // Copy `other` before borrowing this summary mutably,
+        // since the two may be the same object.
+        let other = other.borrow().0;
+        slf.borrow_mut().0.merge(&other);
+    }
+
+    /// Number of values.
+    fn count(&self) -> u64 {
+        self.0.count()
+    }
+
+    /// Arithmetic mean, undefined for no values.
+    fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+
+    /// Population variance, undefined for no values.
+    fn variance(&self) -> Option<f64> {
+        self.0.variance()
+    }
+
+    /// Sample variance, undefined for fewer than two
+    /// values.
+    fn sample_variance(&self) -> Option<f64> {
+        self.0.sample_variance()
+    }
+
+    /// Population standard deviation, undefined for no
+    /// values.
+    fn stddev(&self) -> Option<f64> {
+        self.0.stddev()
+    }
+
+    /// Smallest value, undefined for no values.
+    fn min(&self) -> Option<f64> {
+        self.0.min()
+    }
+
+    /// Largest value, undefined for no values.
+    fn max(&self) -> Option<f64> {
+        self.0.max()
+    }
+
+    /// The summary as JSON, as for `stats::Summary::to_json`.
+    fn to_json(&self) -> String {
+        self.0.to_json()
+    }
+}
+
+/// Outcome of a hypothesis test, as for
+/// `stats::TestResult`.
+#[pyclass(get_all, frozen, skip_from_py_object)]
+#[derive(Debug, Clone)]
+struct TestResult {
+    statistic: f64,
+    df: Option<f64>,
+    p_value: f64,
+}
+
+impl From<crate::TestResult> for TestResult {
+    fn from(r: crate::TestResult) -> TestResult {
+        TestResult {
+            statistic: r.statistic,
+            df: r.df,
+            p_value: r.p_value,
+        }
+    }
+}
+
+/// One-sample t-test, as for `stats::t_test_one_sample`.
+#[pyfunction]
+fn t_test_one_sample(nums: PyReadonlyArray1<f64>, mu0: f64) -> Option<TestResult> {
+    crate::t_test_one_sample(&values(&nums), mu0).map(TestResult::from)
+}
+
+/// Welch's two-sample t-test, as for `stats::t_test_welch`.
+#[pyfunction]
+fn t_test_welch(a: PyReadonlyArray1<f64>, b: PyReadonlyArray1<f64>) -> Option<TestResult> {
+    crate::t_test_welch(&values(&a), &values(&b)).map(TestResult::from)
+}
+
+/// Paired t-test, as for `stats::t_test_paired`.
+#[pyfunction]
+fn t_test_paired(
+    before: PyReadonlyArray1<f64>,
+    after: PyReadonlyArray1<f64>,
+) -> Option<TestResult> {
+    crate::t_test_paired(&values(&before), &values(&after)).map(TestResult::from)
+}
+
+/// Two-sample Kolmogorov–Smirnov test, as for
+/// `stats::ks_test_two`.
+#[pyfunction]
+fn ks_test_two(a: PyReadonlyArray1<f64>, b: PyReadonlyArray1<f64>) -> Option<TestResult> {
+    crate::ks_test_two(&values(&a), &values(&b)).map(TestResult::from)
+}
+
+/// Shapiro–Wilk test of normality, as for
+/// `stats::shapiro_wilk`.
+#[pyfunction]
+fn shapiro_wilk(nums: PyReadonlyArray1<f64>) -> Option<TestResult> {
+    crate::shapiro_wilk(&values(&nums)).map(TestResult::from)
+}
+
+/// Anderson–Darling test of normality, as for
+/// `stats::anderson_darling`.
+#[pyfunction]
+fn anderson_darling(nums: PyReadonlyArray1<f64>) -> Option<TestResult> {
+    crate::anderson_darling(&values(&nums)).map(TestResult::from)
+}
+
+/// The `stats` Python module.
+#[pymodule]
+#[pyo3(name = "stats")]
+fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(mean, m)?)?;
+    m.add_function(wrap_pyfunction!(stddev, m)?)?;
+    m.add_function(wrap_pyfunction!(median, m)?)?;
+    m.add_function(wrap_pyfunction!(mad, m)?)?;
+    m.add_function(wrap_pyfunction!(quantile, m)?)?;
+    m.add_class::<Summary>()?;
+    m.add_class::<TestResult>()?;
+    m.add_function(wrap_pyfunction!(t_test_one_sample, m)?)?;
+    m.add_function(wrap_pyfunction!(t_test_welch, m)?)?;
+    m.add_function(wrap_pyfunction!(t_test_paired, m)?)?;
+    m.add_function(wrap_pyfunction!(ks_test_two, m)?)?;
+    m.add_function(wrap_pyfunction!(shapiro_wilk, m)?)?;
+    m.add_function(wrap_pyfunction!(anderson_darling, m)?)?;
+    Ok(())
+}
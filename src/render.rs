@@ -0,0 +1,131 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Terminal-friendly text renderings of data: ASCII bar
+//! charts and Unicode sparklines.
+
+use crate::Histogram;
+
+/// Sparkline glyphs, from lowest to highest.
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Bars for `values` scaled so that the largest is `width`
+/// characters long, one `#`-bar per line, each preceded by
+/// its label and followed by its value.
+fn bar_lines(labels: &[String], values: &[f64], width: usize) -> String {
+    let label_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let top = values.iter().cloned().fold(0.0, f64::max);
+    let mut chart = String::new();
+    for (label, &v) in labels.iter().zip(values) {
+        let len = if top > 0.0 && v > 0.0 {
+            (v / top * width as f64).round() as usize
+        } else {
+            0
+        };
+        chart += &format!(
+            "{:>w$} |{} {}\n",
+            label,
+            "#".repeat(len),
+            v,
+            w = label_width
+        );
+    }
+    chart
+}
+
+/// ASCII bar chart of a histogram, one line per bin,
+/// scaled so that the fullest bin is `width` characters
+/// long. Bars show the weight in each bin for a weighted
+/// histogram.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let h = Histogram::new(&[0.0, 1.0, 1.5, 3.0], 3).unwrap();
+/// let chart = render::histogram(&h, 4);
+/// assert_eq!("[0, 1) |## 1\n[1, 2) |#### 2\n[2, 3] |## 1\n", chart);
+/// ```
+pub fn histogram(hist: &Histogram, width: usize) -> String {
+    let bins = hist.counts().len();
+    let labels: Vec<String> = hist
+        .edges()
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            let close = if i + 1 == bins { ']' } else { ')' };
+            format!("[{}, {}{}", w[0], w[1], close)
+        })
+        .collect();
+    bar_lines(&labels, &hist.masses(), width)
+}
+
+/// ASCII bar chart of input values, one line per value
+/// labeled with its index, scaled so that the largest value
+/// is `width` characters long. Non-positive values get
+/// empty bars.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let chart = render::bars(&[1.0, 4.0, 2.0], 4);
+/// assert_eq!("0 |# 1\n1 |#### 4\n2 |## 2\n", chart);
+/// ```
+pub fn bars(nums: &[f64], width: usize) -> String {
+    let labels: Vec<String> = (0..nums.len()).map(|i| i.to_string()).collect();
+    bar_lines(&labels, nums, width)
+}
+
+/// Unicode sparkline of input values, one glyph per value,
+/// scaled between the smallest and largest finite values.
+/// Non-finite values are shown as spaces; constant input is
+/// drawn at the lowest level.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!("", render::sparkline(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!("▁▂▃▄▅▆▇█", render::sparkline(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!("▁ █", render::sparkline(&[1.0, f64::NAN, 2.0]));
+/// ```
+pub fn sparkline(nums: &[f64]) -> String {
+    let finite = nums.iter().cloned().filter(|v| v.is_finite());
+    let lo = finite.clone().fold(f64::INFINITY, f64::min);
+    let hi = finite.fold(f64::NEG_INFINITY, f64::max);
+    let top = (SPARKS.len() - 1) as f64;
+    nums.iter()
+        .map(|&v| {
+            if !v.is_finite() {
+                ' '
+            } else if hi > lo {
+                SPARKS[((v - lo) / (hi - lo) * top).round() as usize]
+            } else {
+                SPARKS[0]
+            }
+        })
+        .collect()
+}
+
+/// Unicode sparkline of the bin counts (or weights) of a
+/// histogram.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let h = Histogram::new(&[0.0, 1.0, 1.5, 3.0], 3).unwrap();
+/// assert_eq!("▁█▁", render::histogram_sparkline(&h));
+/// ```
+pub fn histogram_sparkline(hist: &Histogram) -> String {
+    sparkline(&hist.masses())
+}
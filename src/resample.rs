@@ -0,0 +1,280 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Resampling methods. Randomized methods take a seed and
+//! give the same result for the same seed.
+
+use crate::interval::valid_confidence;
+use crate::rng::Rng;
+use crate::special::{normal_cdf, normal_quantile};
+use crate::{sorted, sorted_quantile, ConfidenceInterval};
+
+/// Outcome of a permutation test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermutationResult {
+    /// Observed difference `statistic(a) - statistic(b)`.
+    pub statistic: f64,
+    /// Two-sided p-value: the fraction of permutations,
+    /// counting the observed arrangement, with a difference
+    /// at least as large in magnitude as the observed one.
+    pub p_value: f64,
+    /// Difference for each random permutation.
+    pub distribution: Vec<f64>,
+}
+
+/// Permutation test of whether `statistic` differs between
+/// `a` and `b`. The samples are pooled and randomly
+/// re-split into groups of the original sizes
+/// `n_permutations` times, recomputing the difference
+/// `statistic(a) - statistic(b)` each time. Any `StatFn`
+/// can serve as the statistic. The test is undefined for an
+/// empty sample, for no permutations, or when the statistic
+/// is undefined on any arrangement.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, permutation_test(&[], &[1.0], mean, 100, 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let a = [10.0, 11.0, 12.0, 13.0, 14.0];
+/// let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let r = permutation_test(&a, &b, mean, 999, 7).unwrap();
+/// assert_eq!(9.0, r.statistic);
+/// assert_eq!(999, r.distribution.len());
+/// assert!(r.p_value < 0.02);
+/// assert_eq!(r, permutation_test(&a, &b, mean, 999, 7).unwrap());
+/// ```
+/// ```
+/// # use stats::*;
+/// let r = permutation_test(&[1.0, 3.0], &[2.0, 2.0], median, 99, 1).unwrap();
+/// assert_eq!(1.0, r.p_value);
+/// ```
+pub fn permutation_test<F>(
+    a: &[f64],
+    b: &[f64],
+    statistic: F,
+    n_permutations: usize,
+    seed: u64,
+) -> Option<PermutationResult>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    if a.is_empty() || b.is_empty() || n_permutations == 0 {
+        return None;
+    }
+    let difference = |x: &[f64], y: &[f64]| Some(statistic(x)? - statistic(y)?);
+    let observed = difference(a, b)?;
+    let mut pooled: Vec<f64> = a.iter().chain(b).cloned().collect();
+    let mut rng = Rng::new(seed);
+    let mut distribution = Vec::with_capacity(n_permutations);
+    for _ in 0..n_permutations {
+        rng.shuffle(&mut pooled);
+        let (x, y) = pooled.split_at(a.len());
+        distribution.push(difference(x, y)?);
+    }
+    // Allow for rounding error in differences that are
+    // mathematically equal to the observed one.
+    let threshold = observed.abs() * (1.0 - 1e-12);
+    let extreme = distribution.iter().filter(|d| d.abs() >= threshold).count();
+    Some(PermutationResult {
+        statistic: observed,
+        p_value: (extreme + 1) as f64 / (n_permutations + 1) as f64,
+        distribution,
+    })
+}
+
+/// How `bootstrap_ci` turns the bootstrap distribution into
+/// an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapMethod {
+    /// Quantiles of the bootstrap distribution.
+    Percentile,
+    /// Bias-corrected and accelerated quantiles (Efron
+    /// 1987), with the acceleration from the jackknife.
+    /// More accurate for skewed or biased statistics.
+    Bca,
+}
+
+/// Values of `statistic` with each input value left out in
+/// turn.
+fn leave_one_out<F>(nums: &[f64], statistic: F) -> Option<Vec<f64>>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    let mut rest = nums[1..].to_vec();
+    let mut values = Vec::with_capacity(nums.len());
+    for i in 0..nums.len() {
+        if i > 0 {
+            // Put back value i - 1 in place of value i.
+            rest[i - 1] = nums[i - 1];
+        }
+        values.push(statistic(&rest)?);
+    }
+    Some(values)
+}
+
+/// Bootstrap confidence interval for `statistic` at the
+/// given `confidence` level (such as 0.95), from
+/// `n_resamples` resamples of the input drawn with
+/// replacement. Any `StatFn` can serve as the statistic.
+/// The interval is undefined for an empty list, for no
+/// resamples, for a confidence level outside `(0, 1)`, or
+/// when the statistic is undefined on the input or on any
+/// resample. A BCa interval is also undefined when the
+/// bootstrap distribution lies entirely to one side of the
+/// estimate.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, bootstrap_ci(&[], mean, 1000, 0.95, BootstrapMethod::Percentile, 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let ci = bootstrap_ci(&[2.0, 2.0], mean, 100, 0.95, BootstrapMethod::Percentile, 0).unwrap();
+/// assert_eq!((2.0, 2.0, 2.0), (ci.lower, ci.estimate, ci.upper));
+/// assert_eq!(None, bootstrap_ci(&[2.0, 2.0], mean, 100, 0.95, BootstrapMethod::Bca, 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums: Vec<f64> = (1..=20).map(f64::from).collect();
+/// let ci = bootstrap_ci(&nums, mean, 2000, 0.95, BootstrapMethod::Percentile, 3).unwrap();
+/// assert_eq!(10.5, ci.estimate);
+/// // Close to the normal-theory interval 10.5 ± 2.53.
+/// assert!((ci.lower - 7.97).abs() < 0.3 && (ci.upper - 13.03).abs() < 0.3);
+/// assert_eq!(ci, bootstrap_ci(&nums, mean, 2000, 0.95, BootstrapMethod::Percentile, 3).unwrap());
+/// ```
+/// ```
+/// # use stats::*;
+/// let skewed = [1.0, 1.2, 1.3, 1.5, 1.8, 2.2, 2.9, 4.1, 6.5, 12.0];
+/// let p = bootstrap_ci(&skewed, mean, 4000, 0.9, BootstrapMethod::Percentile, 5).unwrap();
+/// let bca = bootstrap_ci(&skewed, mean, 4000, 0.9, BootstrapMethod::Bca, 5).unwrap();
+/// // BCa shifts the interval toward the long tail.
+/// assert!(bca.lower > p.lower && bca.upper > p.upper);
+/// ```
+pub fn bootstrap_ci<F>(
+    nums: &[f64],
+    statistic: F,
+    n_resamples: usize,
+    confidence: f64,
+    method: BootstrapMethod,
+    seed: u64,
+) -> Option<ConfidenceInterval>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    if nums.is_empty() || n_resamples == 0 || !valid_confidence(confidence) {
+        return None;
+    }
+    let estimate = statistic(nums)?;
+    let mut rng = Rng::new(seed);
+    let mut resample = vec![0.0; nums.len()];
+    let mut distribution = Vec::with_capacity(n_resamples);
+    for _ in 0..n_resamples {
+        for v in resample.iter_mut() {
+            *v = nums[rng.below(nums.len())];
+        }
+        distribution.push(statistic(&resample)?);
+    }
+    let distribution = sorted(&distribution);
+
+    let alpha = 1.0 - confidence;
+    let (q_lower, q_upper) = match method {
+        BootstrapMethod::Percentile => (alpha / 2.0, 1.0 - alpha / 2.0),
+        BootstrapMethod::Bca => {
+            let below = distribution.partition_point(|&d| d < estimate);
+            if below == 0 || below == n_resamples {
+                return None;
+            }
+            let z0 = normal_quantile(below as f64 / n_resamples as f64);
+            let jackknife = leave_one_out(nums, &statistic)?;
+            let jack_mean = jackknife.iter().sum::<f64>() / jackknife.len() as f64;
+            let (mut sum2, mut sum3) = (0.0, 0.0);
+            for d in jackknife.iter().map(|j| jack_mean - j) {
+                sum2 += d * d;
+                sum3 += d * d * d;
+            }
+            let acceleration = if sum2 > 0.0 {
+                sum3 / (6.0 * sum2.powf(1.5))
+            } else {
+                0.0
+            };
+            let adjust = |q: f64| {
+                let z = z0 + normal_quantile(q);
+                normal_cdf(z0 + z / (1.0 - acceleration * z))
+            };
+            (adjust(alpha / 2.0), adjust(1.0 - alpha / 2.0))
+        }
+    };
+    Some(ConfidenceInterval {
+        estimate,
+        lower: sorted_quantile(&distribution, q_lower),
+        upper: sorted_quantile(&distribution, q_upper),
+    })
+}
+
+/// Jackknife estimates for a statistic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JackknifeResult {
+    /// Statistic of the full input.
+    pub estimate: f64,
+    /// Estimated bias of the statistic; subtract it from
+    /// `estimate` for a bias-corrected estimate.
+    pub bias: f64,
+    /// Jackknife standard error of the statistic.
+    pub std_error: f64,
+}
+
+/// Jackknife bias and standard error of `statistic`, from
+/// its values with each input value left out in turn. Any
+/// `StatFn` can serve as the statistic. The estimates are
+/// undefined for fewer than two values, or when the
+/// statistic is undefined on the input or on any
+/// leave-one-out sample.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, jackknife(&[1.0], mean));
+/// ```
+/// ```
+/// # use stats::*;
+/// let j = jackknife(&[1.0, 2.0, 3.0, 4.0], mean).unwrap();
+/// assert_eq!(2.5, j.estimate);
+/// assert!(j.bias.abs() < 1e-15);
+/// // The usual standard error of the mean, s / √n.
+/// assert!((j.std_error - (5.0f64 / 3.0 / 4.0).sqrt()).abs() < 1e-15);
+/// ```
+/// ```
+/// # use stats::*;
+/// // The population variance is biased low; the jackknife
+/// // correction recovers the sample variance.
+/// let variance = |x: &[f64]| stddev(x).map(|s| s * s);
+/// let j = jackknife(&[1.0, 2.0, 3.0, 4.0], variance).unwrap();
+/// assert!((j.estimate - j.bias - 5.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn jackknife<F>(nums: &[f64], statistic: F) -> Option<JackknifeResult>
+where
+    F: Fn(&[f64]) -> Option<f64>,
+{
+    if nums.len() < 2 {
+        return None;
+    }
+    let estimate = statistic(nums)?;
+    let values = leave_one_out(nums, statistic)?;
+    let n = nums.len() as f64;
+    let jack_mean = values.iter().sum::<f64>() / n;
+    let ss = values.iter().map(|v| (v - jack_mean).powi(2)).sum::<f64>();
+    Some(JackknifeResult {
+        estimate,
+        bias: (n - 1.0) * (jack_mean - estimate),
+        std_error: ((n - 1.0) / n * ss).sqrt(),
+    })
+}
@@ -0,0 +1,167 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Robust transformations and estimators.
+
+use crate::{mad, median, quantile, sorted, MAD_SCALE};
+
+/// Relative convergence tolerance of `huber_mean`, in units
+/// of the scale estimate.
+const HUBER_TOLERANCE: f64 = 1e-10;
+
+/// Iteration limit of `huber_mean`.
+const HUBER_ITERATIONS: usize = 100;
+
+/// Copy of input values with each value limited to `[lo,
+/// hi]`. NaN values are left as they are.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(vec![0.0, 0.5, 1.0], clip(&[-2.0, 0.5, 3.0], 0.0, 1.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let c = clip(&[f64::NAN, 0.5], 0.0, 1.0);
+/// assert!(c[0].is_nan());
+/// assert_eq!(0.5, c[1]);
+/// ```
+pub fn clip(nums: &[f64], lo: f64, hi: f64) -> Vec<f64> {
+    nums.iter()
+        .map(|&v| if v.is_nan() { v } else { v.max(lo).min(hi) })
+        .collect()
+}
+
+/// Copy of input values with the tails clamped to the
+/// `lower_q` and `upper_q` quantiles, as computed by
+/// `quantile`. Quantile levels are limited to `[0, 1]`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(winsorize(&[], 0.1, 0.9).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [-100.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 100.0];
+/// let w = winsorize(&nums, 0.1, 0.9);
+/// assert!((w[0] + 9.1).abs() < 1e-12);
+/// assert!((w[9] - 17.2).abs() < 1e-12);
+/// assert_eq!(nums[1..9], w[1..9]);
+/// ```
+pub fn winsorize(nums: &[f64], lower_q: f64, upper_q: f64) -> Vec<f64> {
+    let bound = |q: f64| quantile(nums, q.clamp(0.0, 1.0));
+    match (bound(lower_q), bound(upper_q)) {
+        (Some(lo), Some(hi)) => clip(nums, lo, hi),
+        _ => Vec::new(),
+    }
+}
+
+/// Huber M-estimator of location with tuning constant `k`,
+/// computed by iteratively reweighted means starting from
+/// the median. Residuals are scaled by `1.4826 MAD`; values
+/// within `k` scaled units of the estimate get full weight
+/// and those further out are down-weighted in proportion to
+/// their distance. `k = 1.345` gives 95% efficiency on
+/// normal data. Constant input has the constant as its
+/// estimate. The estimate of an empty list, or for
+/// non-positive `k`, is undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, huber_mean(&[], 1.345));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(2.0), huber_mean(&[2.0, 2.0, 2.0], 1.345));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [1.0, 2.0, 3.0, 4.0, 5.0, 100.0];
+/// let h = huber_mean(&nums, 1.345).unwrap();
+/// assert!(3.0 < h && h < 4.5);
+/// let h = huber_mean(&nums, 1e6).unwrap();
+/// assert!((h - mean(&nums).unwrap()).abs() < 1e-9);
+/// ```
+pub fn huber_mean(nums: &[f64], k: f64) -> Option<f64> {
+    if k.is_nan() || k <= 0.0 {
+        return None;
+    }
+    let mut mu = median(nums)?;
+    let scale = MAD_SCALE * mad(nums)?;
+    if scale == 0.0 {
+        return Some(mu);
+    }
+    let cutoff = k * scale;
+    for _ in 0..HUBER_ITERATIONS {
+        let (weighted, total) = nums.iter().fold((0.0, 0.0), |(wx, w), &v| {
+            let r = (v - mu).abs();
+            let weight = if r <= cutoff { 1.0 } else { cutoff / r };
+            (wx + weight * v, w + weight)
+        });
+        let next = weighted / total;
+        let done = (next - mu).abs() < HUBER_TOLERANCE * scale;
+        mu = next;
+        if done {
+            break;
+        }
+    }
+    Some(mu)
+}
+
+/// Interquartile mean of input values: the mean of the
+/// middle half of the sorted values. When the number of
+/// values is not a multiple of four, the values straddling
+/// the quartiles count with the fraction of them that lies
+/// inside. The interquartile mean of an empty list is
+/// undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, interquartile_mean(&[]));
+/// assert_eq!(Some(3.0), interquartile_mean(&[3.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums = [8.0, 1.0, 7.0, 2.0, 6.0, 3.0, 5.0, 4.0];
+/// assert_eq!(Some(4.5), interquartile_mean(&nums));
+/// ```
+/// ```
+/// # use stats::*;
+/// // Nine values: 3 and 7 each count three-quarters.
+/// let nums = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 100.0, 1000.0];
+/// assert_eq!(Some(5.0), interquartile_mean(&nums));
+/// let nums = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 100.0, f64::INFINITY];
+/// assert_eq!(Some(5.0), interquartile_mean(&nums));
+/// ```
+pub fn interquartile_mean(nums: &[f64]) -> Option<f64> {
+    if nums.is_empty() {
+        return None;
+    }
+    let n = nums.len() as f64;
+    let (lo, hi) = (n / 4.0, 3.0 * n / 4.0);
+    // Value i covers [i, i + 1) of the sorted positions.
+    let total = sorted(nums)
+        .iter()
+        .enumerate()
+        .filter_map(|(i, x)| {
+            let i = i as f64;
+            let weight = (i + 1.0).min(hi) - i.max(lo);
+            // Skip values outside, which may be infinite.
+            if weight > 0.0 {
+                Some(weight * x)
+            } else {
+                None
+            }
+        })
+        .sum::<f64>();
+    Some(total / (hi - lo))
+}
@@ -0,0 +1,201 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Random sampling and splitting of data. Every function
+//! takes a seed and gives the same result for the same
+//! seed.
+
+use crate::rng::Rng;
+use std::collections::BTreeMap;
+
+/// How many values `stratified_sample` takes from each
+/// stratum.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Allocation<L> {
+    /// The same fraction of every stratum, rounded to the
+    /// nearest count.
+    Proportional(f64),
+    /// A given count from each stratum; strata without a
+    /// count contribute nothing.
+    Counts(BTreeMap<L, usize>),
+}
+
+/// `k` distinct indices chosen uniformly from `0..n`, in
+/// ascending order.
+fn choose_indices(rng: &mut Rng, n: usize, k: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..n).collect();
+    for i in 0..k {
+        let j = i + rng.below(n - i);
+        indices.swap(i, j);
+    }
+    indices.truncate(k);
+    indices.sort_unstable();
+    indices
+}
+
+/// Random sample of `values` drawn separately within each
+/// stratum, where `labels[i]` is the stratum of
+/// `values[i]`. Sampled values keep their original order.
+/// The sample is undefined for inputs of unequal length, for
+/// a fraction outside `[0, 1]`, or for a count larger than
+/// its stratum.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, stratified_sample(&[1.0], &["a", "b"], &Allocation::Proportional(0.5), 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let values: Vec<u32> = (0..100).collect();
+/// let labels: Vec<&str> = values.iter().map(|&v| if v < 80 { "ok" } else { "error" }).collect();
+/// let sample = stratified_sample(&values, &labels, &Allocation::Proportional(0.1), 9).unwrap();
+/// assert_eq!(10, sample.len());
+/// assert_eq!(2, sample.iter().filter(|&&v| v >= 80).count());
+/// assert!(sample.windows(2).all(|w| w[0] < w[1]));
+/// ```
+/// ```
+/// # use stats::*;
+/// # use std::collections::BTreeMap;
+/// let counts: BTreeMap<_, _> = vec![("a", 1), ("b", 2)].into_iter().collect();
+/// let allocation = Allocation::Counts(counts);
+/// let sample = stratified_sample(&[1, 2, 3, 4, 5], &["a", "b", "a", "b", "c"], &allocation, 4);
+/// let sample = sample.unwrap();
+/// assert_eq!(3, sample.len());
+/// assert!(sample.contains(&2) && sample.contains(&4) && !sample.contains(&5));
+/// ```
+pub fn stratified_sample<T, L>(
+    values: &[T],
+    labels: &[L],
+    allocation: &Allocation<L>,
+    seed: u64,
+) -> Option<Vec<T>>
+where
+    T: Clone,
+    L: Ord + Clone,
+{
+    if values.len() != labels.len() {
+        return None;
+    }
+    if let Allocation::Proportional(fraction) = allocation {
+        if !(0.0..=1.0).contains(fraction) {
+            return None;
+        }
+    }
+    let mut strata: BTreeMap<&L, Vec<usize>> = BTreeMap::new();
+    for (i, label) in labels.iter().enumerate() {
+        strata.entry(label).or_default().push(i);
+    }
+    let mut rng = Rng::new(seed);
+    let mut chosen = Vec::new();
+    for (label, members) in &strata {
+        let k = match allocation {
+            Allocation::Proportional(fraction) => {
+                (fraction * members.len() as f64).round() as usize
+            }
+            Allocation::Counts(counts) => counts.get(*label).cloned().unwrap_or(0),
+        };
+        if k > members.len() {
+            return None;
+        }
+        chosen.extend(
+            choose_indices(&mut rng, members.len(), k)
+                .into_iter()
+                .map(|j| members[j]),
+        );
+    }
+    chosen.sort_unstable();
+    Some(chosen.into_iter().map(|i| values[i].clone()).collect())
+}
+
+/// Partition of indices into training and test sets, each
+/// in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Split {
+    /// Indices of the training set.
+    pub train: Vec<usize>,
+    /// Indices of the test set.
+    pub test: Vec<usize>,
+}
+
+/// Random split of the indices of `nums` into a training set
+/// and a test set holding `test_fraction` of them, rounded
+/// to the nearest count. The split is undefined for a
+/// fraction outside `[0, 1]`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, train_test_split(&[1.0, 2.0], 1.5, 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let nums: Vec<f64> = (0..10).map(f64::from).collect();
+/// let split = train_test_split(&nums, 0.3, 11).unwrap();
+/// assert_eq!((7, 3), (split.train.len(), split.test.len()));
+/// let mut all: Vec<usize> = split.train.iter().chain(&split.test).cloned().collect();
+/// all.sort();
+/// assert_eq!((0..10).collect::<Vec<_>>(), all);
+/// assert_eq!(split, train_test_split(&nums, 0.3, 11).unwrap());
+/// ```
+pub fn train_test_split<T>(nums: &[T], test_fraction: f64, seed: u64) -> Option<Split> {
+    if !(0.0..=1.0).contains(&test_fraction) {
+        return None;
+    }
+    let n = nums.len();
+    let k = (test_fraction * n as f64).round() as usize;
+    let test = choose_indices(&mut Rng::new(seed), n, k);
+    let train = complement(n, &test);
+    Some(Split { train, test })
+}
+
+/// Indices in `0..n` not in the sorted list `excluded`.
+fn complement(n: usize, excluded: &[usize]) -> Vec<usize> {
+    (0..n)
+        .filter(|i| excluded.binary_search(i).is_err())
+        .collect()
+}
+
+/// Random partition of the indices `0..n` into `k` folds
+/// for cross-validation, whose sizes differ by at most one.
+/// Split `i` tests on fold `i` and trains on the rest. The
+/// folds are undefined unless `2 <= k <= n`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, kfold_indices(3, 4, 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// let folds = kfold_indices(10, 3, 5).unwrap();
+/// let sizes: Vec<usize> = folds.iter().map(|f| f.test.len()).collect();
+/// assert_eq!(vec![4, 3, 3], sizes);
+/// assert!(folds.iter().all(|f| f.train.len() + f.test.len() == 10));
+/// let mut tested: Vec<usize> = folds.iter().flat_map(|f| f.test.clone()).collect();
+/// tested.sort();
+/// assert_eq!((0..10).collect::<Vec<_>>(), tested);
+/// ```
+pub fn kfold_indices(n: usize, k: usize, seed: u64) -> Option<Vec<Split>> {
+    if k < 2 || k > n {
+        return None;
+    }
+    let mut order: Vec<usize> = (0..n).collect();
+    Rng::new(seed).shuffle(&mut order);
+    let mut folds = Vec::with_capacity(k);
+    let mut start = 0;
+    for i in 0..k {
+        let size = n / k + usize::from(i < n % k);
+        let mut test = order[start..start + size].to_vec();
+        test.sort_unstable();
+        start += size;
+        let train = complement(n, &test);
+        folds.push(Split { train, test });
+    }
+    Some(folds)
+}
@@ -0,0 +1,73 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of Polars columns. Null entries are left
+//! out, as if absent from the data, and columns split into
+//! several chunks are handled chunk by chunk where
+//! possible. A float `Series` gives its chunked array with
+//! `Series::f64`.
+
+use crate::Summary;
+use polars_core::prelude::*;
+
+/// Statistics of a Polars `Float64` column.
+pub trait ColumnStats {
+    /// Summary of the non-null values, computed over each
+    /// chunk in turn.
+    fn summary(&self) -> Summary;
+
+    /// Value of `statistic` on the non-null values.
+    /// Values are copied unless the column is a single
+    /// chunk without nulls. Any `StatFn` can serve as the
+    /// statistic.
+    fn statistic<F>(&self, statistic: F) -> Option<f64>
+    where
+        F: Fn(&[f64]) -> Option<f64>;
+}
+
+/// # Examples:
+///
+/// ```
+/// # use stats::series::*;
+/// use polars_core::prelude::*;
+/// let mut x = Float64Chunked::from_slice_options("x".into(), &[Some(1.0), None, Some(2.0)]);
+/// x.append(&Float64Chunked::from_slice("y".into(), &[6.0])).unwrap();
+/// let s = x.summary();
+/// assert_eq!(3, s.count());
+/// assert_eq!(Some(3.0), s.mean());
+/// assert_eq!(Some(2.0), x.statistic(stats::median));
+/// ```
+/// ```
+/// # use stats::series::*;
+/// use polars_core::prelude::*;
+/// let series = Series::new("x".into(), &[4.0, 1.0, 3.0, 2.0]);
+/// let x = series.f64().unwrap();
+/// assert_eq!(Some(2.5), x.statistic(|v| stats::quantile(v, 0.5)));
+/// ```
+impl ColumnStats for Float64Chunked {
+    fn summary(&self) -> Summary {
+        let mut summary = Summary::new();
+        for chunk in self.downcast_iter() {
+            chunk.iter().flatten().for_each(|&x| summary.push(x));
+        }
+        summary
+    }
+
+    fn statistic<F>(&self, statistic: F) -> Option<f64>
+    where
+        F: Fn(&[f64]) -> Option<f64>,
+    {
+        match self.cont_slice() {
+            Ok(values) => statistic(values),
+            Err(_) => {
+                let values: Vec<f64> = self
+                    .downcast_iter()
+                    .flat_map(|chunk| chunk.iter().flatten().copied())
+                    .collect();
+                statistic(&values)
+            }
+        }
+    }
+}
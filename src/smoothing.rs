@@ -0,0 +1,208 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Smoothers that estimate an underlying signal from noisy
+//! data by local polynomial fitting.
+
+use crate::cmp_f64;
+
+/// Solution of the square linear system `matrix · x = rhs`
+/// by Gaussian elimination with partial pivoting, or `None`
+/// if the matrix is singular.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    for col in 0..n {
+        let pivot =
+            (col..n).max_by(|&a, &b| cmp_f64(&matrix[a][col].abs(), &matrix[b][col].abs()))?;
+        if matrix[pivot][col] == 0.0 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let (done, rest) = matrix.split_at_mut(col + 1);
+        let pivot_row = &done[col];
+        for (offset, row) in rest.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+            rhs[col + 1 + offset] -= factor * rhs[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known = (row + 1..n).map(|k| matrix[row][k] * x[k]).sum::<f64>();
+        x[row] = (rhs[row] - known) / matrix[row][row];
+    }
+    Some(x)
+}
+
+/// Weights that, applied to values at offsets `-h..=h`,
+/// give the value at offset `u` of their least-squares
+/// polynomial of degree `order`.
+fn savitzky_golay_weights(h: usize, order: usize, u: f64) -> Option<Vec<f64>> {
+    let offsets: Vec<f64> = (0..=2 * h).map(|j| j as f64 - h as f64).collect();
+    // Normal equations: (AᵀA) c = Aᵀ y for the design matrix
+    // A[j][k] = offset_j^k. Solving (AᵀA) z = (1, u, u², …)
+    // gives weights A z, since the fitted value at u is
+    // (1, u, …) · c.
+    let normal: Vec<Vec<f64>> = (0..=order)
+        .map(|a| {
+            (0..=order)
+                .map(|b| offsets.iter().map(|t| t.powi((a + b) as i32)).sum())
+                .collect()
+        })
+        .collect();
+    let powers: Vec<f64> = (0..=order).map(|k| u.powi(k as i32)).collect();
+    let z = solve(normal, powers)?;
+    Some(
+        offsets
+            .iter()
+            .map(|t| {
+                z.iter()
+                    .enumerate()
+                    .map(|(k, zk)| zk * t.powi(k as i32))
+                    .sum()
+            })
+            .collect(),
+    )
+}
+
+/// Savitzky–Golay smoothing of an equally spaced series:
+/// each value is replaced by the value of a least-squares
+/// polynomial of degree `poly_order` fitted over a `window`
+/// of values centered on it. Values within half a window of
+/// either end come from the polynomial fitted to the first
+/// or last full window. Unlike a moving average, peaks keep
+/// their height. The smoothed series is undefined unless
+/// the window is odd, no longer than the series, and
+/// longer than the polynomial order.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, savitzky_golay(&[1.0, 2.0, 3.0, 4.0], 4, 2));
+/// ```
+/// ```
+/// # use stats::*;
+/// let mut impulse = vec![0.0; 9];
+/// impulse[4] = 1.0;
+/// let s = savitzky_golay(&impulse, 5, 2).unwrap();
+/// let expected = [-3.0, 12.0, 17.0, 12.0, -3.0];
+/// assert!(s[2..7].iter().zip(&expected).all(|(s, e)| (s - e / 35.0).abs() < 1e-12));
+/// ```
+/// ```
+/// # use stats::*;
+/// // Polynomials up to the order are left unchanged.
+/// let quadratic: Vec<f64> = (0..10).map(|i| (i * i) as f64).collect();
+/// let s = savitzky_golay(&quadratic, 5, 2).unwrap();
+/// assert!(s.iter().zip(&quadratic).all(|(s, q)| (s - q).abs() < 1e-9));
+/// ```
+pub fn savitzky_golay(nums: &[f64], window: usize, poly_order: usize) -> Option<Vec<f64>> {
+    let n = nums.len();
+    if window.is_multiple_of(2) || window > n || poly_order >= window {
+        return None;
+    }
+    let h = window / 2;
+    let apply = |weights: &[f64], start: usize| {
+        weights
+            .iter()
+            .zip(&nums[start..start + window])
+            .map(|(w, x)| w * x)
+            .sum::<f64>()
+    };
+    let center = savitzky_golay_weights(h, poly_order, 0.0)?;
+    let mut smoothed = Vec::with_capacity(n);
+    for i in 0..n {
+        let value = if i < h {
+            apply(
+                &savitzky_golay_weights(h, poly_order, i as f64 - h as f64)?,
+                0,
+            )
+        } else if i + h >= n {
+            let offset = (i + h + 1 - n) as f64;
+            apply(&savitzky_golay_weights(h, poly_order, offset)?, n - window)
+        } else {
+            apply(&center, i - h)
+        };
+        smoothed.push(value);
+    }
+    Some(smoothed)
+}
+
+/// LOESS smoothing of `y` against `x`: at each `x[i]`, a
+/// straight line fitted by least squares to the nearest
+/// `span` fraction of the points, weighted by the tricube
+/// of their distance. Returns the fitted values at each
+/// `x[i]`. The smooth is undefined for inputs of unequal
+/// length, for a span outside `(0, 1]`, or when the span
+/// covers fewer than two points.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, loess(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0], 0.5));
+/// ```
+/// ```
+/// # use stats::*;
+/// // Lines are reproduced exactly.
+/// let x = [0.0, 1.0, 2.0, 4.0, 7.0, 8.0];
+/// let y: Vec<f64> = x.iter().map(|x| 3.0 - 0.5 * x).collect();
+/// let fit = loess(&x, &y, 0.5).unwrap();
+/// assert!(fit.iter().zip(&y).all(|(f, y)| (f - y).abs() < 1e-12));
+/// ```
+/// ```
+/// # use stats::*;
+/// let x: Vec<f64> = (0..20).map(f64::from).collect();
+/// let y: Vec<f64> = x.iter().map(|&x| x + if x as usize % 2 == 0 { 1.0 } else { -1.0 }).collect();
+/// let fit = loess(&x, &y, 0.3).unwrap();
+/// assert!(fit[5..15].iter().zip(&x[5..15]).all(|(f, x)| (f - x).abs() < 0.25));
+/// ```
+pub fn loess(x: &[f64], y: &[f64], span: f64) -> Option<Vec<f64>> {
+    let n = x.len();
+    if n != y.len() || !(span > 0.0 && span <= 1.0) {
+        return None;
+    }
+    let q = (span * n as f64).floor() as usize;
+    if q < 2 {
+        return None;
+    }
+    let mut fitted = Vec::with_capacity(n);
+    let mut distances = vec![0.0; n];
+    for &x0 in x {
+        for (d, xi) in distances.iter_mut().zip(x) {
+            *d = (xi - x0).abs();
+        }
+        let mut nearest = distances.clone();
+        nearest.sort_by(cmp_f64);
+        let radius = nearest[q - 1];
+        let weights: Vec<f64> = distances
+            .iter()
+            .map(|&d| {
+                if d > radius {
+                    0.0
+                } else if radius == 0.0 {
+                    1.0
+                } else {
+                    (1.0 - (d / radius).powi(3)).powi(3)
+                }
+            })
+            .collect();
+        let total = weights.iter().sum::<f64>();
+        let weighted_mean =
+            |v: &[f64]| weights.iter().zip(v).map(|(w, v)| w * v).sum::<f64>() / total;
+        let (mx, my) = (weighted_mean(x), weighted_mean(y));
+        let (mut sxy, mut sxx) = (0.0, 0.0);
+        for ((w, xi), yi) in weights.iter().zip(x).zip(y) {
+            sxy += w * (xi - mx) * (yi - my);
+            sxx += w * (xi - mx) * (xi - mx);
+        }
+        let slope = if sxx > 0.0 { sxy / sxx } else { 0.0 };
+        fitted.push(my + slope * (x0 - mx));
+    }
+    Some(fitted)
+}
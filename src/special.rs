@@ -0,0 +1,343 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Special functions underlying the probability
+//! distributions.
+
+use std::f64::consts::{PI, SQRT_2};
+
+/// Relative accuracy targeted by the iterative
+/// approximations.
+const EPSILON: f64 = 1e-15;
+
+/// Iteration limit for series and continued fractions.
+const MAX_ITERATIONS: usize = 1000;
+
+/// Natural logarithm of the gamma function for `x > 0`,
+/// by the Lanczos approximation (g = 7, n = 9).
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula.
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = COEFFS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFS[0], |s, (i, c)| s + c / (x + (i + 1) as f64));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Regularized lower incomplete gamma function `P(a, x)`
+/// for `a > 0`, `x >= 0`.
+pub(crate) fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x < a + 1.0 {
+        gamma_series(a, x)
+    } else {
+        1.0 - gamma_continued_fraction(a, x)
+    }
+}
+
+/// Regularized upper incomplete gamma function
+/// `Q(a, x) = 1 - P(a, x)`, accurate in the upper tail.
+pub(crate) fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x < a + 1.0 {
+        1.0 - gamma_series(a, x)
+    } else {
+        gamma_continued_fraction(a, x)
+    }
+}
+
+/// `P(a, x)` by its power series, for `x < a + 1`.
+fn gamma_series(a: f64, x: f64) -> f64 {
+    let mut term = 1.0 / a;
+    let mut sum = term;
+    for n in 1..MAX_ITERATIONS {
+        term *= x / (a + n as f64);
+        sum += term;
+        if term.abs() < sum.abs() * EPSILON {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+/// `Q(a, x)` by its continued fraction (modified Lentz), for
+/// `x >= a + 1`.
+fn gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    let tiny = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+/// Upper-tail probability of the chi-square distribution
+/// with `df > 0` degrees of freedom.
+pub(crate) fn chi_square_sf(x: f64, df: f64) -> f64 {
+    gamma_q(0.5 * df, 0.5 * x)
+}
+
+/// Upper-tail probability `Q(λ) = 2 Σ (-1)^(k-1) e^(-2k²λ²)`
+/// of the Kolmogorov distribution.
+pub(crate) fn kolmogorov_sf(lambda: f64) -> f64 {
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+    for k in 1..=100 {
+        let k = k as f64;
+        let term = sign * (-2.0 * k * k * lambda * lambda).exp();
+        sum += term;
+        if term.abs() <= EPSILON * sum.abs() {
+            return (2.0 * sum).clamp(0.0, 1.0);
+        }
+        sign = -sign;
+    }
+    // The series fails to converge only for small lambda,
+    // where the probability is indistinguishable from 1.
+    1.0
+}
+
+/// Complementary error function.
+pub(crate) fn erfc(x: f64) -> f64 {
+    if x >= 0.0 {
+        gamma_q(0.5, x * x)
+    } else {
+        1.0 + gamma_p(0.5, x * x)
+    }
+}
+
+/// Standard normal cumulative distribution function.
+pub(crate) fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// Standard normal quantile function (probit) for `p` in
+/// `(0, 1)`, by Acklam's rational approximation refined
+/// with a Halley step to full double precision. Returns
+/// infinities at 0 and 1 and NaN outside `[0, 1]`.
+pub(crate) fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    if !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    let x = if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    };
+    // Halley refinement, working in whichever tail is more
+    // accurately represented.
+    let e = if x < 0.0 {
+        normal_cdf(x) - p
+    } else {
+        (1.0 - p) - normal_cdf(-x)
+    };
+    let u = e * (2.0 * PI).sqrt() * (0.5 * x * x).exp();
+    x - u / (1.0 + 0.5 * x * u)
+}
+
+/// Regularized incomplete beta function `I_x(a, b)` for
+/// `a, b > 0` and `x` in `[0, 1]`.
+pub(crate) fn beta_inc(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges quickly only on one
+    // side of the mean; use the symmetry relation on the
+    // other.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction for the incomplete beta function
+/// (modified Lentz).
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let tiny = 1e-300;
+    let clamp = |v: f64| if v.abs() < tiny { tiny } else { v };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..MAX_ITERATIONS {
+        let m = m as f64;
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / clamp(1.0 + even * d);
+        c = clamp(1.0 + even / c);
+        h *= d * c;
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / clamp(1.0 + odd * d);
+        c = clamp(1.0 + odd / c);
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Value `x` at which the increasing function `cdf` reaches
+/// `p`, for `p` strictly between the limits of `cdf`, by
+/// bisection to full precision. The domain of `cdf` starts
+/// at `lower`, which may be `-∞`.
+pub(crate) fn invert_cdf<F: Fn(f64) -> f64>(cdf: F, p: f64, lower: f64) -> f64 {
+    let mut hi = 1.0;
+    while cdf(hi) < p && hi < f64::MAX / 2.0 {
+        hi *= 2.0;
+    }
+    let mut lo = if lower.is_finite() { lower } else { -1.0 };
+    while !lower.is_finite() && cdf(lo) > p && lo > f64::MIN / 2.0 {
+        lo *= 2.0;
+    }
+    loop {
+        let mid = lo + (hi - lo) / 2.0;
+        if mid <= lo || mid >= hi {
+            return mid;
+        }
+        if cdf(mid) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+/// Cumulative distribution function of Student's t
+/// distribution with `df > 0` degrees of freedom.
+pub(crate) fn student_t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * beta_inc(0.5 * df, 0.5, df / (df + t * t));
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// Quantile function of Student's t distribution with
+/// `df > 0` degrees of freedom.
+pub(crate) fn student_t_quantile(p: f64, df: f64) -> f64 {
+    if !(0.0..=1.0).contains(&p) {
+        f64::NAN
+    } else if p == 0.0 {
+        f64::NEG_INFINITY
+    } else if p == 1.0 {
+        f64::INFINITY
+    } else if p > 0.5 {
+        -student_t_quantile(1.0 - p, df)
+    } else {
+        invert_cdf(|t| student_t_cdf(t, df), p, f64::NEG_INFINITY)
+    }
+}
+
+/// Cumulative distribution function of the noncentral t
+/// distribution with `df > 0` degrees of freedom and
+/// noncentrality `delta`, by the Poisson-mixture series of
+/// Lenth (Applied Statistics algorithm AS 243).
+pub(crate) fn noncentral_t_cdf(t: f64, df: f64, delta: f64) -> f64 {
+    if t < 0.0 {
+        return 1.0 - noncentral_t_cdf(-t, df, -delta);
+    }
+    let x = t * t / (t * t + df);
+    let lambda = 0.5 * delta * delta;
+    // Sum far enough past the mode of the Poisson weights
+    // that the remaining terms are negligible.
+    let terms = (lambda + 10.0 * lambda.sqrt() + 50.0) as usize;
+    let mut sum = 0.0;
+    for j in 0..terms {
+        let j = j as f64;
+        let log_poisson = if j == 0.0 { 0.0 } else { j * lambda.ln() };
+        let p = (-lambda + log_poisson - ln_gamma(j + 1.0)).exp();
+        let q = delta * (-lambda + log_poisson - ln_gamma(j + 1.5)).exp() / SQRT_2;
+        sum += p * beta_inc(j + 0.5, 0.5 * df, x) + q * beta_inc(j + 1.0, 0.5 * df, x);
+    }
+    (normal_cdf(-delta) + 0.5 * sum).clamp(0.0, 1.0)
+}
@@ -0,0 +1,224 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! One-pass summary statistics.
+
+/// Running summary of a stream of values: count, mean,
+/// variance, minimum and maximum, updated one value at a
+/// time in constant space (Welford's method). Summaries of
+/// separate parts of the data can be merged.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// # #[cfg(feature = "serde")]
+/// # {
+/// for s in [Summary::new(), Summary::of(&[1.0, -2.0])] {
+///     let json = serde_json::to_string(&s).unwrap();
+///     assert_eq!(s, serde_json::from_str::<Summary>(&json).unwrap());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Summary {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    // Options rather than infinite sentinels, so that an
+    // empty summary survives formats without infinities.
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Default for Summary {
+    fn default() -> Summary {
+        Summary::new()
+    }
+}
+
+impl Summary {
+    /// Summary of no values.
+    pub fn new() -> Summary {
+        Summary {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Summary of input values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let s = Summary::of(&[1.0, -2.0]);
+    /// assert_eq!(2, s.count());
+    /// assert_eq!(Some(-0.5), s.mean());
+    /// assert_eq!(stddev(&[1.0, -2.0]), s.stddev());
+    /// assert_eq!((Some(-2.0), Some(1.0)), (s.min(), s.max()));
+    /// ```
+    pub fn of(nums: &[f64]) -> Summary {
+        let mut summary = Summary::new();
+        nums.iter().for_each(|&x| summary.push(x));
+        summary
+    }
+
+    /// Add a value to the summary.
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = Some(self.min.map_or(x, |m| m.min(x)));
+        self.max = Some(self.max.map_or(x, |m| m.max(x)));
+    }
+
+    /// Combine with the summary of other values, as if they
+    /// had all been pushed here (Chan et al.).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let mut s = Summary::of(&[1.0, 2.0]);
+    /// s.merge(&Summary::of(&[3.0, 4.0, 5.0]));
+    /// let all = Summary::of(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(all.count(), s.count());
+    /// assert_eq!(all.mean(), s.mean());
+    /// assert!((all.variance().unwrap() - s.variance().unwrap()).abs() < 1e-15);
+    /// ```
+    pub fn merge(&mut self, other: &Summary) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.mean += delta * weight;
+        self.count = count;
+        self.min = bound(self.min, other.min, f64::min);
+        self.max = bound(self.max, other.max, f64::max);
+    }
+
+    /// Number of values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Arithmetic mean, undefined for no values.
+    pub fn mean(&self) -> Option<f64> {
+        self.defined(self.mean)
+    }
+
+    /// Population variance, undefined for no values.
+    pub fn variance(&self) -> Option<f64> {
+        self.defined(self.m2 / self.count as f64)
+    }
+
+    /// Sample (Bessel-corrected) variance, undefined for
+    /// fewer than two values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// assert_eq!(None, Summary::of(&[1.0]).sample_variance());
+    /// assert_eq!(Some(0.5), Summary::of(&[1.0, 2.0]).sample_variance());
+    /// ```
+    pub fn sample_variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count - 1) as f64)
+        }
+    }
+
+    /// Population standard deviation, undefined for no
+    /// values.
+    pub fn stddev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Smallest value, undefined for no values.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// Largest value, undefined for no values.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// The summary as a JSON object with the fields `count`,
+    /// `mean`, `variance`, `stddev`, `min` and `max`. The
+    /// count is an integer; the other fields are numbers, or
+    /// `null` where undefined.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use stats::*;
+    /// let s = Summary::of(&[1.0, -2.0]);
+    /// let json = r#"{"count":2,"mean":-0.5,"variance":2.25,"stddev":1.5,"min":-2,"max":1}"#;
+    /// assert_eq!(json, s.to_json());
+    /// ```
+    /// ```
+    /// # use stats::*;
+    /// let json = r#"{"count":0,"mean":null,"variance":null,"stddev":null,"min":null,"max":null}"#;
+    /// assert_eq!(json, Summary::new().to_json());
+    /// ```
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"count":{},"mean":{},"variance":{},"stddev":{},"min":{},"max":{}}}"#,
+            self.count,
+            json_number(self.mean()),
+            json_number(self.variance()),
+            json_number(self.stddev()),
+            json_number(self.min()),
+            json_number(self.max()),
+        )
+    }
+
+    fn defined(&self, value: f64) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(value)
+        }
+    }
+}
+
+/// Combination of two optional bounds by `pick`, either
+/// being absent for no values.
+fn bound(a: Option<f64>, b: Option<f64>, pick: fn(f64, f64) -> f64) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(pick(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// JSON text of a number: `null` if it is undefined or not
+/// finite, since JSON has no NaN or infinities.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!("2.5", json_number(Some(2.5)));
+/// assert_eq!("null", json_number(None));
+/// assert_eq!("null", json_number(Some(f64::NAN)));
+/// ```
+pub fn json_number(x: Option<f64>) -> String {
+    match x {
+        Some(x) if x.is_finite() => x.to_string(),
+        _ => "null".to_string(),
+    }
+}
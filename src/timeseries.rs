@@ -0,0 +1,360 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Statistics of time series: equally spaced observations
+//! in time order.
+
+use crate::{mad, mean, stddev, MAD_SCALE};
+
+/// Additive decomposition of a series into trend, seasonal
+/// and residual components, with `series[i] = trend[i] +
+/// seasonal[i] + residual[i]` wherever the trend is
+/// defined.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decomposition {
+    /// Centered moving average, undefined within half a
+    /// period of either end.
+    pub trend: Vec<Option<f64>>,
+    /// Repeating seasonal pattern, summing to zero over a
+    /// period.
+    pub seasonal: Vec<f64>,
+    /// What remains after removing trend and seasonality.
+    pub residual: Vec<Option<f64>>,
+}
+
+/// Classical additive decomposition of a series with
+/// seasonality of the given `period`: the trend is a
+/// centered moving average over one period (a 2×`period`
+/// average for even periods), and the seasonal component
+/// the average detrended value at each phase of the period.
+/// The decomposition is undefined for a period less than 2,
+/// or for fewer than two full periods of data.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, decompose(&[1.0, 2.0, 3.0], 2));
+/// ```
+/// ```
+/// # use stats::*;
+/// let weekly = [3.0, -1.0, -1.0, -1.0];
+/// let series: Vec<f64> = (0..12).map(|i| i as f64 + weekly[i % 4]).collect();
+/// let d = decompose(&series, 4).unwrap();
+/// assert_eq!(None, d.trend[1]);
+/// assert_eq!(Some(2.0), d.trend[2]);
+/// for (s, w) in d.seasonal.iter().zip(weekly.iter().cycle()) {
+///     assert!((s - w).abs() < 1e-12);
+/// }
+/// assert!(d.residual.iter().flatten().all(|r| r.abs() < 1e-12));
+/// ```
+pub fn decompose(nums: &[f64], period: usize) -> Option<Decomposition> {
+    let n = nums.len();
+    if period < 2 || n < 2 * period {
+        return None;
+    }
+    let half = period / 2;
+    let mut trend = vec![None; n];
+    for (i, t) in trend.iter_mut().enumerate().take(n - half).skip(half) {
+        let window = &nums[i - half..=i + half];
+        let sum = if period % 2 == 1 {
+            window.iter().sum::<f64>()
+        } else {
+            // The end points straddle the period and share
+            // its weight.
+            window.iter().sum::<f64>() - 0.5 * (window[0] + window[period])
+        };
+        *t = Some(sum / period as f64);
+    }
+
+    let mut phase_sums = vec![0.0; period];
+    let mut phase_counts = vec![0; period];
+    for (i, (x, t)) in nums.iter().zip(&trend).enumerate() {
+        if let Some(t) = t {
+            phase_sums[i % period] += x - t;
+            phase_counts[i % period] += 1;
+        }
+    }
+    let figures: Vec<f64> = phase_sums
+        .iter()
+        .zip(&phase_counts)
+        .map(|(s, &c)| s / c as f64)
+        .collect();
+    let level = figures.iter().sum::<f64>() / period as f64;
+    let seasonal: Vec<f64> = (0..n).map(|i| figures[i % period] - level).collect();
+    let residual = nums
+        .iter()
+        .zip(&trend)
+        .zip(&seasonal)
+        .map(|((x, t), s)| t.map(|t| x - t - s))
+        .collect();
+    Some(Decomposition {
+        trend,
+        seasonal,
+        residual,
+    })
+}
+
+/// Differences `nums[i + lag] - nums[i]` of a series, one
+/// shorter than the series for each step of lag. The
+/// differences are undefined for a lag of zero.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, diff(&[1.0, 2.0], 0));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(vec![2.0, 3.0, 4.0]), diff(&[1.0, 3.0, 6.0, 10.0], 1));
+/// assert_eq!(Some(vec![5.0, 7.0]), diff(&[1.0, 3.0, 6.0, 10.0], 2));
+/// assert_eq!(Some(vec![]), diff(&[1.0], 1));
+/// ```
+pub fn diff(nums: &[f64], lag: usize) -> Option<Vec<f64>> {
+    if lag == 0 {
+        return None;
+    }
+    Some(
+        nums.iter()
+            .zip(nums.iter().skip(lag))
+            .map(|(a, b)| b - a)
+            .collect(),
+    )
+}
+
+/// Series with its least-squares linear trend over time
+/// removed. The detrended series is undefined for an empty
+/// series.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, detrend_linear(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let d = detrend_linear(&[1.0, 4.0, 5.0, 8.0]).unwrap();
+/// let expected = [-0.2, 0.6, -0.6, 0.2];
+/// assert!(d.iter().zip(&expected).all(|(d, e)| (d - e).abs() < 1e-12));
+/// ```
+pub fn detrend_linear(nums: &[f64]) -> Option<Vec<f64>> {
+    let detrended = detrend_mean(nums)?;
+    let n = nums.len() as f64;
+    let t_mean = (n - 1.0) / 2.0;
+    let t_ss = (0..nums.len())
+        .map(|t| (t as f64 - t_mean).powi(2))
+        .sum::<f64>();
+    if t_ss == 0.0 {
+        return Some(detrended);
+    }
+    let slope = detrended
+        .iter()
+        .enumerate()
+        .map(|(t, x)| (t as f64 - t_mean) * x)
+        .sum::<f64>()
+        / t_ss;
+    Some(
+        detrended
+            .iter()
+            .enumerate()
+            .map(|(t, x)| x - slope * (t as f64 - t_mean))
+            .collect(),
+    )
+}
+
+/// Series with its mean removed. The detrended series is
+/// undefined for an empty series.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, detrend_mean(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(vec![-1.0, 0.0, 1.0]), detrend_mean(&[1.0, 2.0, 3.0]));
+/// ```
+pub fn detrend_mean(nums: &[f64]) -> Option<Vec<f64>> {
+    if nums.is_empty() {
+        return None;
+    }
+    let m = mean(nums)?;
+    Some(nums.iter().map(|x| x - m).collect())
+}
+
+/// Durbin–Watson statistic of regression residuals in time
+/// order, `Σ (e[t] - e[t-1])² / Σ e[t]²`. It ranges from 0
+/// to 4: values near 2 indicate no first-order
+/// autocorrelation, values toward 0 positive and toward 4
+/// negative autocorrelation. The statistic is undefined for
+/// fewer than two residuals or for all-zero residuals.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, durbin_watson(&[0.0, 0.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(3.0), durbin_watson(&[1.0, -1.0, 1.0, -1.0]));
+/// assert_eq!(Some(0.0), durbin_watson(&[1.0, 1.0, 1.0]));
+/// assert_eq!(Some(2.0), durbin_watson(&[1.0, -1.0, -1.0, 1.0]));
+/// ```
+pub fn durbin_watson(residuals: &[f64]) -> Option<f64> {
+    if residuals.len() < 2 {
+        return None;
+    }
+    let ss = residuals.iter().map(|e| e * e).sum::<f64>();
+    if ss == 0.0 {
+        return None;
+    }
+    let differences = residuals
+        .windows(2)
+        .map(|w| (w[1] - w[0]).powi(2))
+        .sum::<f64>();
+    Some(differences / ss)
+}
+
+/// Shortest segment `change_points` will report.
+pub const CHANGE_POINT_MIN_SEGMENT: usize = 2;
+
+/// Indexes at which the mean level of a series shifts, each
+/// the start of a new segment, found by PELT (Killick et
+/// al. 2012): the exact minimum over all segmentations of
+/// the within-segment sum of squares, plus a BIC-style
+/// penalty of `2 ln n` noise variances per change. The noise
+/// variance is estimated robustly from the MAD of
+/// consecutive differences, so the level shifts themselves
+/// do not inflate it. Segments have at least
+/// `CHANGE_POINT_MIN_SEGMENT` values. Series too short to
+/// split, or with no variation, have no change points.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert!(change_points(&[1.0, 1.0, 1.0, 1.0, 1.0]).is_empty());
+/// ```
+/// ```
+/// # use stats::*;
+/// let noise = [0.1, -0.2, 0.15, -0.1, 0.05, 0.2, -0.15, 0.0, -0.05, 0.1];
+/// let levels = [0.0, 0.0, 0.0, 5.0, 5.0, 5.0, 5.0, 2.0, 2.0, 2.0];
+/// let mut series = Vec::new();
+/// for level in levels.iter() {
+///     series.extend(noise.iter().map(|e| level + e));
+/// }
+/// assert_eq!(vec![30, 70], change_points(&series));
+/// ```
+/// ```
+/// # use stats::*;
+/// let step = [0.0, 0.0, 0.0, 4.0, 4.0, 4.0];
+/// assert_eq!(vec![3], change_points(&step));
+/// ```
+pub fn change_points(nums: &[f64]) -> Vec<usize> {
+    let n = nums.len();
+    let min_len = CHANGE_POINT_MIN_SEGMENT;
+    if n < 2 * min_len {
+        return Vec::new();
+    }
+    // Differences cancel the level, leaving √2 times the
+    // noise.
+    let differences = diff(nums, 1).unwrap_or_default();
+    let mut sigma = mad(&differences).unwrap_or(0.0) * MAD_SCALE / 2f64.sqrt();
+    if sigma == 0.0 {
+        sigma = stddev(&differences).unwrap_or(0.0) / 2f64.sqrt();
+    }
+    if sigma == 0.0 {
+        return Vec::new();
+    }
+    let variance = sigma * sigma;
+    let penalty = 2.0 * (n as f64).ln();
+
+    let mut sums = vec![0.0; n + 1];
+    let mut squares = vec![0.0; n + 1];
+    for (i, x) in nums.iter().enumerate() {
+        sums[i + 1] = sums[i] + x;
+        squares[i + 1] = squares[i] + x * x;
+    }
+    let cost = |a: usize, b: usize| {
+        let sum = sums[b] - sums[a];
+        (squares[b] - squares[a] - sum * sum / (b - a) as f64) / variance
+    };
+
+    // best[t]: penalized cost of the best segmentation of
+    // the first t values, whose last segment starts at
+    // last[t].
+    let mut best = vec![f64::INFINITY; n + 1];
+    let mut last = vec![0; n + 1];
+    best[0] = -penalty;
+    let mut candidates = vec![0];
+    for t in min_len..=n {
+        if t >= 2 * min_len {
+            candidates.push(t - min_len);
+        }
+        for &s in &candidates {
+            let total = best[s] + cost(s, t) + penalty;
+            if total < best[t] {
+                best[t] = total;
+                last[t] = s;
+            }
+        }
+        // A start that cannot beat the best now never will.
+        candidates.retain(|&s| best[s] + cost(s, t) <= best[t]);
+    }
+
+    let mut points = Vec::new();
+    let mut t = n;
+    while last[t] > 0 {
+        t = last[t];
+        points.push(t);
+    }
+    points.reverse();
+    points
+}
+
+/// Drawdown at each point of a cumulative series: how far it
+/// has fallen below its running maximum so far, zero at
+/// each new peak. Divide by the running maximum for
+/// drawdowns as a fraction of the peak.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let equity = [100.0, 120.0, 90.0, 110.0, 130.0, 125.0];
+/// assert_eq!(vec![0.0, 0.0, 30.0, 10.0, 0.0, 5.0], drawdowns(&equity));
+/// ```
+pub fn drawdowns(nums: &[f64]) -> Vec<f64> {
+    let mut peak = f64::NEG_INFINITY;
+    nums.iter()
+        .map(|&x| {
+            peak = peak.max(x);
+            peak - x
+        })
+        .collect()
+}
+
+/// Largest drawdown of a cumulative series: the worst fall
+/// from a peak to a later trough. The maximum drawdown of
+/// an empty series is undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, max_drawdown(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(30.0), max_drawdown(&[100.0, 120.0, 90.0, 110.0, 130.0, 125.0]));
+/// assert_eq!(Some(0.0), max_drawdown(&[1.0, 2.0, 3.0]));
+/// ```
+pub fn max_drawdown(nums: &[f64]) -> Option<f64> {
+    drawdowns(nums).into_iter().reduce(f64::max)
+}
@@ -0,0 +1,179 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! The core statistics with uniform semantics. Every
+//! function here fails with `StatsError::EmptyInput` for
+//! empty input, where the crate-root functions variously
+//! return `None` or a conventional value such as a mean
+//! of 0. NaN is never silently propagated or sorted: it
+//! fails with `StatsError::NanInput`, naming the first
+//! NaN, and callers that mean to ignore NaN can drop it
+//! first with `without_nan`. The median averages the two
+//! middle values.
+
+use crate::StatsError;
+
+/// Fail for empty input or input containing NaN.
+fn check(nums: &[f64]) -> Result<(), StatsError> {
+    if nums.is_empty() {
+        return Err(StatsError::EmptyInput);
+    }
+    match nums.iter().position(|x| x.is_nan()) {
+        Some(index) => Err(StatsError::NanInput { index }),
+        None => Ok(()),
+    }
+}
+
+/// Result of a crate-root statistic on checked input.
+fn checked(nums: &[f64], statistic: fn(&[f64]) -> Option<f64>) -> Result<f64, StatsError> {
+    check(nums)?;
+    statistic(nums).ok_or(StatsError::EmptyInput)
+}
+
+/// Input values other than NaN.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(vec![1.0, 2.0], v2::without_nan(&[1.0, f64::NAN, 2.0]));
+/// ```
+pub fn without_nan(nums: &[f64]) -> Vec<f64> {
+    nums.iter().cloned().filter(|x| !x.is_nan()).collect()
+}
+
+/// Arithmetic mean of input values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::{v2, StatsError};
+/// assert!(matches!(v2::mean(&[]), Err(StatsError::EmptyInput)));
+/// assert!(matches!(v2::mean(&[1.0, f64::NAN]), Err(StatsError::NanInput { index: 1 })));
+/// assert_eq!(2.0, v2::mean(&[1.0, 2.0, 3.0]).unwrap());
+/// ```
+pub fn mean(nums: &[f64]) -> Result<f64, StatsError> {
+    checked(nums, crate::mean)
+}
+
+/// Population variance of input values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(2.25, v2::variance(&[1.0, -2.0]).unwrap());
+/// assert_eq!(14.0 / 3.0, v2::variance(&[0.0, 1.0, 5.0]).unwrap());
+/// ```
+pub fn variance(nums: &[f64]) -> Result<f64, StatsError> {
+    checked(nums, population_variance)
+}
+
+/// Mean squared deviation from the mean, undefined for an
+/// empty list.
+fn population_variance(nums: &[f64]) -> Option<f64> {
+    if nums.is_empty() {
+        return None;
+    }
+    let m = crate::mean(nums)?;
+    let ss = nums.iter().map(|x| (x - m).powi(2)).sum::<f64>();
+    Some(ss / nums.len() as f64)
+}
+
+/// Population standard deviation of input values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(1.5, v2::stddev(&[1.0, -2.0]).unwrap());
+/// ```
+pub fn stddev(nums: &[f64]) -> Result<f64, StatsError> {
+    Ok(variance(nums)?.sqrt())
+}
+
+/// Median of input values, averaging the two middle values
+/// for an even number of them.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(0.25, v2::median(&[0.0, 0.5, -1.0, 1.0]).unwrap());
+/// ```
+pub fn median(nums: &[f64]) -> Result<f64, StatsError> {
+    checked(nums, crate::median_interpolated)
+}
+
+/// Quantile `q` of input values, as for `stats::quantile`.
+/// A `q` outside `[0, 1]` fails with
+/// `StatsError::OutOfRange`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::{v2, StatsError};
+/// assert_eq!(1.75, v2::quantile(&[4.0, 1.0, 3.0, 2.0], 0.25).unwrap());
+/// assert!(matches!(v2::quantile(&[1.0], 1.5), Err(StatsError::OutOfRange { .. })));
+/// ```
+pub fn quantile(nums: &[f64], q: f64) -> Result<f64, StatsError> {
+    check(nums)?;
+    crate::quantile(nums, q).ok_or(StatsError::OutOfRange {
+        name: "q",
+        value: q,
+    })
+}
+
+/// Median absolute deviation of input values, as for
+/// `stats::mad`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(1.0, v2::mad(&[1.0, 2.0, 3.0, 4.0, 100.0]).unwrap());
+/// ```
+pub fn mad(nums: &[f64]) -> Result<f64, StatsError> {
+    checked(nums, crate::mad)
+}
+
+/// L2 norm of input values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::{v2, StatsError};
+/// assert_eq!(5.0, v2::l2(&[-3.0, 4.0]).unwrap());
+/// assert!(matches!(v2::l2(&[]), Err(StatsError::EmptyInput)));
+/// ```
+pub fn l2(nums: &[f64]) -> Result<f64, StatsError> {
+    checked(nums, crate::l2)
+}
+
+/// Smallest input value.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(-2.0, v2::min(&[1.0, -2.0]).unwrap());
+/// ```
+pub fn min(nums: &[f64]) -> Result<f64, StatsError> {
+    check(nums)?;
+    Ok(nums.iter().cloned().fold(f64::INFINITY, f64::min))
+}
+
+/// Largest input value.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::v2;
+/// assert_eq!(1.0, v2::max(&[1.0, -2.0]).unwrap());
+/// ```
+pub fn max(nums: &[f64]) -> Result<f64, StatsError> {
+    check(nums)?;
+    Ok(nums.iter().cloned().fold(f64::NEG_INFINITY, f64::max))
+}
@@ -0,0 +1,140 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Checking input for non-finite values, and strict
+//! variants of the core statistics that refuse them.
+
+use std::fmt;
+
+/// Kind of non-finite value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonFinite {
+    /// Not a number.
+    Nan,
+    /// Positive infinity.
+    PosInfinity,
+    /// Negative infinity.
+    NegInfinity,
+}
+
+/// Report of the non-finite values in some input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Position and kind of each non-finite value, in input
+    /// order.
+    pub issues: Vec<(usize, NonFinite)>,
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} non-finite input values:", self.issues.len())?;
+        for (index, kind) in &self.issues {
+            let kind = match kind {
+                NonFinite::Nan => "NaN",
+                NonFinite::PosInfinity => "+inf",
+                NonFinite::NegInfinity => "-inf",
+            };
+            write!(f, " {} at {}", kind, index)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+/// Check that every input value is finite, reporting the
+/// position and kind of any that are not.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(Ok(()), validate(&[1.0, 2.0]));
+/// ```
+/// ```
+/// # use stats::*;
+/// let report = validate(&[1.0, f64::NAN, f64::INFINITY, 2.0, f64::NEG_INFINITY]).unwrap_err();
+/// let expected = vec![
+///     (1, NonFinite::Nan),
+///     (2, NonFinite::PosInfinity),
+///     (4, NonFinite::NegInfinity),
+/// ];
+/// assert_eq!(expected, report.issues);
+/// assert_eq!("3 non-finite input values: NaN at 1 +inf at 2 -inf at 4", report.to_string());
+/// ```
+pub fn validate(nums: &[f64]) -> Result<(), ValidationReport> {
+    let issues: Vec<(usize, NonFinite)> = nums
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &x)| {
+            if x.is_nan() {
+                Some((i, NonFinite::Nan))
+            } else if x == f64::INFINITY {
+                Some((i, NonFinite::PosInfinity))
+            } else if x == f64::NEG_INFINITY {
+                Some((i, NonFinite::NegInfinity))
+            } else {
+                None
+            }
+        })
+        .collect();
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationReport { issues })
+    }
+}
+
+/// `mean` of input values that `validate` accepts.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(Ok(Some(2.0)), mean_strict(&[1.0, 3.0]));
+/// assert_eq!(vec![(1, NonFinite::Nan)], mean_strict(&[1.0, f64::NAN]).unwrap_err().issues);
+/// ```
+pub fn mean_strict(nums: &[f64]) -> Result<Option<f64>, ValidationReport> {
+    validate(nums)?;
+    Ok(crate::mean(nums))
+}
+
+/// `stddev` of input values that `validate` accepts.
+pub fn stddev_strict(nums: &[f64]) -> Result<Option<f64>, ValidationReport> {
+    validate(nums)?;
+    Ok(crate::stddev(nums))
+}
+
+/// `median` of input values that `validate` accepts.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(Ok(None), median_strict(&[]));
+/// assert!(median_strict(&[f64::NEG_INFINITY]).is_err());
+/// ```
+pub fn median_strict(nums: &[f64]) -> Result<Option<f64>, ValidationReport> {
+    validate(nums)?;
+    Ok(crate::median(nums))
+}
+
+/// `l2` of input values that `validate` accepts.
+pub fn l2_strict(nums: &[f64]) -> Result<Option<f64>, ValidationReport> {
+    validate(nums)?;
+    Ok(crate::l2(nums))
+}
+
+/// `quantile` of input values that `validate` accepts.
+pub fn quantile_strict(nums: &[f64], q: f64) -> Result<Option<f64>, ValidationReport> {
+    validate(nums)?;
+    Ok(crate::quantile(nums, q))
+}
+
+/// `mad` of input values that `validate` accepts.
+pub fn mad_strict(nums: &[f64]) -> Result<Option<f64>, ValidationReport> {
+    validate(nums)?;
+    Ok(crate::mad(nums))
+}
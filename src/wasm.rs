@@ -0,0 +1,139 @@
+// Copyright © 2019 Jubal Gonzalez-Santos
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! JavaScript bindings, built with `wasm-bindgen`. The
+//! functions take a `Float64Array` and return a number, or
+//! `undefined` where the statistic is undefined; `Summary`
+//! is the running summary. Arrays are copied once into
+//! WebAssembly memory; to summarize data arriving in
+//! pieces without keeping it, push each piece to a
+//! `Summary`.
+
+use wasm_bindgen::prelude::*;
+
+/// Arithmetic mean, as for `stats::mean`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::wasm::*;
+/// assert_eq!(Some(2.0), mean(&[1.0, 2.0, 3.0]));
+/// ```
+#[wasm_bindgen]
+pub fn mean(nums: &[f64]) -> Option<f64> {
+    crate::mean(nums)
+}
+
+/// Population standard deviation, as for `stats::stddev`.
+#[wasm_bindgen]
+pub fn stddev(nums: &[f64]) -> Option<f64> {
+    crate::stddev(nums)
+}
+
+/// Median, as for `stats::median`.
+#[wasm_bindgen]
+pub fn median(nums: &[f64]) -> Option<f64> {
+    crate::median(nums)
+}
+
+/// Median absolute deviation, as for `stats::mad`.
+#[wasm_bindgen]
+pub fn mad(nums: &[f64]) -> Option<f64> {
+    crate::mad(nums)
+}
+
+/// Quantile `q`, as for `stats::quantile`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::wasm::*;
+/// assert_eq!(Some(2.5), quantile(&[4.0, 1.0, 3.0, 2.0], 0.5));
+/// assert_eq!(None, quantile(&[], 0.5));
+/// ```
+#[wasm_bindgen]
+pub fn quantile(nums: &[f64], q: f64) -> Option<f64> {
+    crate::quantile(nums, q)
+}
+
+/// Running summary of a stream of values, as for
+/// `stats::Summary`.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::wasm::*;
+/// let mut s = Summary::new();
+/// s.push_all(&[1.0, 2.0]);
+/// s.push(6.0);
+/// assert_eq!(3.0, s.count());
+/// assert_eq!(Some(3.0), s.mean());
+/// assert_eq!(Some(6.0), s.max());
+/// ```
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct Summary(crate::Summary);
+
+#[wasm_bindgen]
+impl Summary {
+    /// Summary of no values.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Summary {
+        Summary(crate::Summary::new())
+    }
+
+    /// Add a value to the summary.
+    pub fn push(&mut self, x: f64) {
+        self.0.push(x);
+    }
+
+    /// Add each of the values to the summary.
+    #[wasm_bindgen(js_name = pushAll)]
+    pub fn push_all(&mut self, nums: &[f64]) {
+        nums.iter().for_each(|&x| self.0.push(x));
+    }
+
+    /// Fold in the summary of other values.
+    pub fn merge(&mut self, other: &Summary) {
+        self.0.merge(&other.0);
+    }
+
+    /// Number of values, as a JavaScript number.
+    pub fn count(&self) -> f64 {
+        self.0.count() as f64
+    }
+
+    /// Arithmetic mean, undefined for no values.
+    pub fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+
+    /// Population variance, undefined for no values.
+    pub fn variance(&self) -> Option<f64> {
+        self.0.variance()
+    }
+
+    /// Population standard deviation, undefined for no
+    /// values.
+    pub fn stddev(&self) -> Option<f64> {
+        self.0.stddev()
+    }
+
+    /// Smallest value, undefined for no values.
+    pub fn min(&self) -> Option<f64> {
+        self.0.min()
+    }
+
+    /// Largest value, undefined for no values.
+    pub fn max(&self) -> Option<f64> {
+        self.0.max()
+    }
+
+    /// The summary as JSON, as for `stats::Summary::to_json`.
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> String {
+        self.0.to_json()
+    }
+}